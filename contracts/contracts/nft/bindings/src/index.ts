@@ -33,24 +33,63 @@ if (typeof window !== 'undefined') {
 
 
 
+export const Errors = {
+  1: {message:"AlreadyInitialized"},
+  2: {message:"NotInitialized"},
+  3: {message:"TokenNotFound"},
+  4: {message:"NotTokenOwner"},
+  5: {message:"NoPendingOwner"},
+  6: {message:"NotApproved"},
+  7: {message:"InvalidLiveUntilLedger"},
+  8: {message:"InvalidRoyalty"},
+  9: {message:"RoyaltyNotSet"},
+  10: {message:"CollectionNotFound"},
+  11: {message:"InvalidMaxSupply"},
+  12: {message:"CollectionSoldOut"},
+  13: {message:"InvalidSalePrice"}
+}
 
-export interface NFTMetadata {
-  description: SorobanString;
-  image_url: SorobanString;
-  name: SorobanString;
+export type DataKey = {tag: "Admin", values: void} | {tag: "PendingAdmin", values: void} | {tag: "Version", values: void} | {tag: "MigrationCursor", values: void} | {tag: "TokenOwner", values: readonly [u32]} | {tag: "TokenMetadata", values: readonly [u32]} | {tag: "Name", values: void} | {tag: "TokenSymbol", values: void} | {tag: "TotalSupply", values: void} | {tag: "TotalMinted", values: void} | {tag: "TotalBurned", values: void} | {tag: "Approval", values: readonly [u32]} | {tag: "OperatorApproval", values: readonly [string, string]} | {tag: "OwnerBalance", values: readonly [string]} | {tag: "OwnedToken", values: readonly [string, u32]} | {tag: "OwnedTokenIndex", values: readonly [u32]} | {tag: "DefaultRoyalty", values: void} | {tag: "TokenRoyalty", values: readonly [u32]} | {tag: "CollectionCount", values: void} | {tag: "Collection", values: readonly [u32]} | {tag: "CollectionRoyalty", values: readonly [u32]} | {tag: "TokenEdition", values: readonly [u32]};
+
+
+export interface Edition {
+  collection_id: u32;
+  max_supply: u32;
+  number: u32;
 }
 
-export type DataKey = {tag: "Admin", values: void} | {tag: "TokenOwner", values: readonly [u32]} | {tag: "TokenMetadata", values: readonly [u32]} | {tag: "TotalSupply", values: void};
 
-export const Errors = {
+export interface Royalty {
+  bps: u32;
+  receiver: string;
+}
+
 
+export interface Collection {
+  creator: string;
+  max_supply: u32;
+  metadata: NFTMetadata;
+  minted: u32;
+}
+
+
+export interface NFTMetadata {
+  description: string;
+  image_url: string;
+  name: string;
+}
+
+
+export interface ApprovalData {
+  approved: string;
+  live_until_ledger: u32;
 }
 
 export interface Client {
   /**
-   * Construct and simulate a initialize transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a burn transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  initialize: ({admin}: {admin: string}, options?: {
+  burn: ({from, token_id}: {from: string, token_id: u32}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -65,12 +104,52 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>
+  }) => Promise<AssembledTransaction<Result<void>>>
 
   /**
    * Construct and simulate a mint transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  mint: ({to, name, description, image_url}: {to: string, name: SorobanString, description: SorobanString, image_url: SorobanString}, options?: {
+  mint: ({to, name, description, image_url, royalty}: {to: string, name: string, description: string, image_url: string, royalty: Option<Royalty>}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a name transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  name: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<string>>>
+
+  /**
+   * Construct and simulate a symbol transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  symbol: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -85,7 +164,127 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<u32>>
+  }) => Promise<AssembledTransaction<Result<string>>>
+
+  /**
+   * Construct and simulate a approve transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  approve: ({approver, approved, token_id, live_until_ledger}: {approver: string, approved: string, token_id: u32, live_until_ledger: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a balance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  balance: ({owner}: {owner: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a migrate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  migrate: ({max_tokens}: {max_tokens: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a version transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  version: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a owner_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  owner_of: ({token_id}: {token_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<string>>>
 
   /**
    * Construct and simulate a transfer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -105,7 +304,227 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a burn_from transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  burn_from: ({spender, from, token_id}: {spender: string, from: string, token_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_admin: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<string>>>
+
+  /**
+   * Construct and simulate a token_uri transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  token_uri: ({token_id}: {token_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<string>>>
+
+  /**
+   * Construct and simulate a admin_burn transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  admin_burn: ({token_id}: {token_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a edition_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  edition_of: ({token_id}: {token_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<Option<Edition>>>>
+
+  /**
+   * Construct and simulate a initialize transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  initialize: ({admin, name, symbol}: {admin: string, name: string, symbol: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_approved transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_approved: ({token_id}: {token_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<Option<string>>>>
+
+  /**
+   * Construct and simulate a mint_edition transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  mint_edition: ({to, collection_id}: {to: string, collection_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a royalty_info transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  royalty_info: ({token_id, sale_price}: {token_id: u32, sale_price: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<readonly [string, i128]>>>
+
+  /**
+   * Construct and simulate a total_burned transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  total_burned: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a total_minted transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  total_minted: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u32>>>
 
   /**
    * Construct and simulate a total_supply transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -125,12 +544,52 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<u32>>
+  }) => Promise<AssembledTransaction<Result<u32>>>
 
   /**
-   * Construct and simulate a owner_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a propose_owner transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  owner_of: ({token_id}: {token_id: u32}, options?: {
+  propose_owner: ({new_admin}: {new_admin: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a transfer_from transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  transfer_from: ({spender, from, to, token_id}: {spender: string, from: string, to: string, token_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_collection transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_collection: ({collection_id}: {collection_id: u32}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -145,7 +604,7 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<string>>
+  }) => Promise<AssembledTransaction<Result<Collection>>>
 
   /**
    * Construct and simulate a token_metadata transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -165,12 +624,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<NFTMetadata>>
+  }) => Promise<AssembledTransaction<Result<NFTMetadata>>>
 
   /**
-   * Construct and simulate a token_uri transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a approve_for_all transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  token_uri: ({token_id}: {token_id: u32}, options?: {
+  approve_for_all: ({owner, operator, live_until_ledger}: {owner: string, operator: string, live_until_ledger: u32}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -185,12 +644,152 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<SorobanString>>
+  }) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a tokens_of_owner transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_admin: (options?: {
+  tokens_of_owner: ({owner, start, limit}: {owner: string, start: u32, limit: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<Array<u32>>>>
+
+  /**
+   * Construct and simulate a accept_ownership transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  accept_ownership: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a remaining_supply transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  remaining_supply: ({collection_id}: {collection_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a create_collection transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  create_collection: ({creator, metadata, max_supply, royalty}: {creator: string, metadata: NFTMetadata, max_supply: u32, royalty: Option<Royalty>}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a get_pending_owner transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_pending_owner: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<Option<string>>>>
+
+  /**
+   * Construct and simulate a is_approved_for_all transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  is_approved_for_all: ({owner, operator}: {owner: string, operator: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<boolean>>>
+
+  /**
+   * Construct and simulate a set_collection_info transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_collection_info: ({name, symbol}: {name: string, symbol: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_default_royalty transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_default_royalty: ({royalty}: {royalty: Option<Royalty>}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -205,12 +804,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<string>>
+  }) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a balance_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a cancel_ownership_transfer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  balance_of: ({owner}: {owner: string}, options?: {
+  cancel_ownership_transfer: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -225,12 +824,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<u32>>
+  }) => Promise<AssembledTransaction<Result<void>>>
 
 }
 export class Client extends ContractClient {
   static async deploy<T = Client>(
-    /** Options for initalizing a Client as well as for calling a method, with extras specific to deploying. */
+    /** Options for initializing a Client as well as for calling a method, with extras specific to deploying. */
     options: MethodOptions &
       Omit<ContractClientOptions, "contractId"> & {
         /** The hash of the Wasm blob, which must already be installed on-chain. */
@@ -245,29 +844,90 @@ export class Client extends ContractClient {
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAAAQAAAAAAAAAAAAAAC05GVE1ldGFkYXRhAAAAAAMAAAAAAAAAC2Rlc2NyaXB0aW9uAAAAB9AAAAANU29yb2JhblN0cmluZwAAAAAAAAAAAAAJaW1hZ2VfdXJsAAAAAAAH0AAAAA1Tb3JvYmFuU3RyaW5nAAAAAAAAAAAAAARuYW1lAAAH0AAAAA1Tb3JvYmFuU3RyaW5nAAAA",
-        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAABAAAAAAAAAAAAAAABUFkbWluAAAAAAAAAQAAAAAAAAAKVG9rZW5Pd25lcgAAAAAAAQAAAAQAAAABAAAAAAAAAA1Ub2tlbk1ldGFkYXRhAAAAAAAAAQAAAAQAAAAAAAAAAAAAAAtUb3RhbFN1cHBseQA=",
-        "AAAAAAAAAAAAAAAKaW5pdGlhbGl6ZQAAAAAAAQAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAA==",
-        "AAAAAAAAAAAAAAAEbWludAAAAAQAAAAAAAAAAnRvAAAAAAATAAAAAAAAAARuYW1lAAAH0AAAAA1Tb3JvYmFuU3RyaW5nAAAAAAAAAAAAAAtkZXNjcmlwdGlvbgAAAAfQAAAADVNvcm9iYW5TdHJpbmcAAAAAAAAAAAAACWltYWdlX3VybAAAAAAAB9AAAAANU29yb2JhblN0cmluZwAAAAAAAAEAAAAE",
-        "AAAAAAAAAAAAAAAIdHJhbnNmZXIAAAADAAAAAAAAAARmcm9tAAAAEwAAAAAAAAACdG8AAAAAABMAAAAAAAAACHRva2VuX2lkAAAABAAAAAA=",
-        "AAAAAAAAAAAAAAAMdG90YWxfc3VwcGx5AAAAAAAAAAEAAAAE",
-        "AAAAAAAAAAAAAAAIb3duZXJfb2YAAAABAAAAAAAAAAh0b2tlbl9pZAAAAAQAAAABAAAAEw==",
-        "AAAAAAAAAAAAAAAOdG9rZW5fbWV0YWRhdGEAAAAAAAEAAAAAAAAACHRva2VuX2lkAAAABAAAAAEAAAfQAAAAC05GVE1ldGFkYXRhAA==",
-        "AAAAAAAAAAAAAAAJdG9rZW5fdXJpAAAAAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAEAAAAAQAAB9AAAAANU29yb2JhblN0cmluZwAAAA==",
-        "AAAAAAAAAAAAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAAT",
-        "AAAAAAAAAAAAAAAKYmFsYW5jZV9vZgAAAAAAAQAAAAAAAAAFb3duZXIAAAAAAAATAAAAAQAAAAQ=" ]),
+      new ContractSpec([ "AAAAAAAAAAAAAAAEYnVybgAAAAIAAAAAAAAABGZyb20AAAATAAAAAAAAAAh0b2tlbl9pZAAAAAQAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAAAAAAAEbWludAAAAAUAAAAAAAAAAnRvAAAAAAATAAAAAAAAAARuYW1lAAAAEAAAAAAAAAALZGVzY3JpcHRpb24AAAAAEAAAAAAAAAAJaW1hZ2VfdXJsAAAAAAAAEAAAAAAAAAAHcm95YWx0eQAAAAPoAAAH0AAAAAdSb3lhbHR5AAAAAAEAAAPpAAAABAAAAAM=",
+        "AAAAAAAAAAAAAAAEbmFtZQAAAAAAAAABAAAD6QAAABAAAAAD",
+        "AAAAAAAAAAAAAAAGc3ltYm9sAAAAAAAAAAAAAQAAA+kAAAAQAAAAAw==",
+        "AAAAAAAAAAAAAAAHYXBwcm92ZQAAAAAEAAAAAAAAAAhhcHByb3ZlcgAAABMAAAAAAAAACGFwcHJvdmVkAAAAEwAAAAAAAAAIdG9rZW5faWQAAAAEAAAAAAAAABFsaXZlX3VudGlsX2xlZGdlcgAAAAAAAAQAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAAAAAAAHYmFsYW5jZQAAAAABAAAAAAAAAAVvd25lcgAAAAAAABMAAAABAAAD6QAAAAQAAAAD",
+        "AAAAAAAAAAAAAAAHbWlncmF0ZQAAAAABAAAAAAAAAAptYXhfdG9rZW5zAAAAAAAEAAAAAQAAA+kAAAAEAAAAAw==",
+        "AAAAAAAAAAAAAAAHdXBncmFkZQAAAAABAAAAAAAAAA1uZXdfd2FzbV9oYXNoAAAAAAAD7gAAACAAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAAAAAAAHdmVyc2lvbgAAAAAAAAAAAQAAA+kAAAAEAAAAAw==",
+        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAADQAAAAAAAAASQWxyZWFkeUluaXRpYWxpemVkAAAAAAABAAAAAAAAAA5Ob3RJbml0aWFsaXplZAAAAAAAAgAAAAAAAAANVG9rZW5Ob3RGb3VuZAAAAAAAAAMAAAAAAAAADU5vdFRva2VuT3duZXIAAAAAAAAEAAAAAAAAAA5Ob1BlbmRpbmdPd25lcgAAAAAABQAAAAAAAAALTm90QXBwcm92ZWQAAAAABgAAAAAAAAAWSW52YWxpZExpdmVVbnRpbExlZGdlcgAAAAAABwAAAAAAAAAOSW52YWxpZFJveWFsdHkAAAAAAAgAAAAAAAAADVJveWFsdHlOb3RTZXQAAAAAAAAJAAAAAAAAABJDb2xsZWN0aW9uTm90Rm91bmQAAAAAAAoAAAAAAAAAEEludmFsaWRNYXhTdXBwbHkAAAALAAAAAAAAABFDb2xsZWN0aW9uU29sZE91dAAAAAAAAAwAAAAAAAAAEEludmFsaWRTYWxlUHJpY2UAAAAN",
+        "AAAAAAAAAAAAAAAIb3duZXJfb2YAAAABAAAAAAAAAAh0b2tlbl9pZAAAAAQAAAABAAAD6QAAABMAAAAD",
+        "AAAAAAAAAAAAAAAIdHJhbnNmZXIAAAADAAAAAAAAAARmcm9tAAAAEwAAAAAAAAACdG8AAAAAABMAAAAAAAAACHRva2VuX2lkAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAAAAAAAJYnVybl9mcm9tAAAAAAAAAwAAAAAAAAAHc3BlbmRlcgAAAAATAAAAAAAAAARmcm9tAAAAEwAAAAAAAAAIdG9rZW5faWQAAAAEAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAAAAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAPpAAAAEwAAAAM=",
+        "AAAAAAAAAAAAAAAJdG9rZW5fdXJpAAAAAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAEAAAAAQAAA+kAAAAQAAAAAw==",
+        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAFgAAAAAAAAAAAAAABUFkbWluAAAAAAAAAAAAAAAAAAAMUGVuZGluZ0FkbWluAAAAAAAAAAAAAAAHVmVyc2lvbgAAAAAAAAAAAAAAAA9NaWdyYXRpb25DdXJzb3IAAAAAAQAAAAAAAAAKVG9rZW5Pd25lcgAAAAAAAQAAAAQAAAABAAAAAAAAAA1Ub2tlbk1ldGFkYXRhAAAAAAAAAQAAAAQAAAAAAAAAAAAAAAROYW1lAAAAAAAAAAAAAAALVG9rZW5TeW1ib2wAAAAAAAAAAAAAAAALVG90YWxTdXBwbHkAAAAAAAAAAAAAAAALVG90YWxNaW50ZWQAAAAAAAAAAAAAAAALVG90YWxCdXJuZWQAAAAAAQAAAAAAAAAIQXBwcm92YWwAAAABAAAABAAAAAEAAAAAAAAAEE9wZXJhdG9yQXBwcm92YWwAAAACAAAAEwAAABMAAAABAAAAAAAAAAxPd25lckJhbGFuY2UAAAABAAAAEwAAAAEAAAAAAAAACk93bmVkVG9rZW4AAAAAAAIAAAATAAAABAAAAAEAAAAAAAAAD093bmVkVG9rZW5JbmRleAAAAAABAAAABAAAAAAAAAAAAAAADkRlZmF1bHRSb3lhbHR5AAAAAAABAAAAAAAAAAxUb2tlblJveWFsdHkAAAABAAAABAAAAAAAAAAAAAAAD0NvbGxlY3Rpb25Db3VudAAAAAABAAAAAAAAAApDb2xsZWN0aW9uAAAAAAABAAAABAAAAAEAAAAAAAAAEUNvbGxlY3Rpb25Sb3lhbHR5AAAAAAAAAQAAAAQAAAABAAAAAAAAAAxUb2tlbkVkaXRpb24AAAABAAAABA==",
+        "AAAAAQAAAAAAAAAAAAAAB0VkaXRpb24AAAAAAwAAAAAAAAANY29sbGVjdGlvbl9pZAAAAAAAAAQAAAAAAAAACm1heF9zdXBwbHkAAAAAAAQAAAAAAAAABm51bWJlcgAAAAAABA==",
+        "AAAAAQAAAAAAAAAAAAAAB1JveWFsdHkAAAAAAgAAAAAAAAADYnBzAAAAAAQAAAAAAAAACHJlY2VpdmVyAAAAEw==",
+        "AAAAAAAAAAAAAAAKYWRtaW5fYnVybgAAAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAEAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAAAAAAAKZWRpdGlvbl9vZgAAAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAEAAAAAQAAA+kAAAPoAAAH0AAAAAdFZGl0aW9uAAAAAAM=",
+        "AAAAAAAAAAAAAAAKaW5pdGlhbGl6ZQAAAAAAAwAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAARuYW1lAAAAEAAAAAAAAAAGc3ltYm9sAAAAAAAQAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAAAAAAAMZ2V0X2FwcHJvdmVkAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAEAAAAAQAAA+kAAAPoAAAAEwAAAAM=",
+        "AAAAAAAAAAAAAAAMbWludF9lZGl0aW9uAAAAAgAAAAAAAAACdG8AAAAAABMAAAAAAAAADWNvbGxlY3Rpb25faWQAAAAAAAAEAAAAAQAAA+kAAAAEAAAAAw==",
+        "AAAAAAAAAAAAAAAMcm95YWx0eV9pbmZvAAAAAgAAAAAAAAAIdG9rZW5faWQAAAAEAAAAAAAAAApzYWxlX3ByaWNlAAAAAAALAAAAAQAAA+kAAAPtAAAAAgAAABMAAAALAAAAAw==",
+        "AAAAAAAAAAAAAAAMdG90YWxfYnVybmVkAAAAAAAAAAEAAAPpAAAABAAAAAM=",
+        "AAAAAAAAAAAAAAAMdG90YWxfbWludGVkAAAAAAAAAAEAAAPpAAAABAAAAAM=",
+        "AAAAAAAAAAAAAAAMdG90YWxfc3VwcGx5AAAAAAAAAAEAAAPpAAAABAAAAAM=",
+        "AAAAAQAAAAAAAAAAAAAACkNvbGxlY3Rpb24AAAAAAAQAAAAAAAAAB2NyZWF0b3IAAAAAEwAAAAAAAAAKbWF4X3N1cHBseQAAAAAABAAAAAAAAAAIbWV0YWRhdGEAAAfQAAAAC05GVE1ldGFkYXRhAAAAAAAAAAAGbWludGVkAAAAAAAE",
+        "AAAAAAAAAAAAAAANcHJvcG9zZV9vd25lcgAAAAAAAAEAAAAAAAAACW5ld19hZG1pbgAAAAAAABMAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAAAAAAANdHJhbnNmZXJfZnJvbQAAAAAAAAQAAAAAAAAAB3NwZW5kZXIAAAAAEwAAAAAAAAAEZnJvbQAAABMAAAAAAAAAAnRvAAAAAAATAAAAAAAAAAh0b2tlbl9pZAAAAAQAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAQAAAAAAAAAAAAAAC05GVE1ldGFkYXRhAAAAAAMAAAAAAAAAC2Rlc2NyaXB0aW9uAAAAABAAAAAAAAAACWltYWdlX3VybAAAAAAAABAAAAAAAAAABG5hbWUAAAAQ",
+        "AAAAAAAAAAAAAAAOZ2V0X2NvbGxlY3Rpb24AAAAAAAEAAAAAAAAADWNvbGxlY3Rpb25faWQAAAAAAAAEAAAAAQAAA+kAAAfQAAAACkNvbGxlY3Rpb24AAAAAAAM=",
+        "AAAAAAAAAAAAAAAOdG9rZW5fbWV0YWRhdGEAAAAAAAEAAAAAAAAACHRva2VuX2lkAAAABAAAAAEAAAPpAAAH0AAAAAtORlRNZXRhZGF0YQAAAAAD",
+        "AAAAAQAAAAAAAAAAAAAADEFwcHJvdmFsRGF0YQAAAAIAAAAAAAAACGFwcHJvdmVkAAAAEwAAAAAAAAARbGl2ZV91bnRpbF9sZWRnZXIAAAAAAAAE",
+        "AAAAAAAAAAAAAAAPYXBwcm92ZV9mb3JfYWxsAAAAAAMAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAIb3BlcmF0b3IAAAATAAAAAAAAABFsaXZlX3VudGlsX2xlZGdlcgAAAAAAAAQAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAAAAAAAPdG9rZW5zX29mX293bmVyAAAAAAMAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAFc3RhcnQAAAAAAAAEAAAAAAAAAAVsaW1pdAAAAAAAAAQAAAABAAAD6QAAA+oAAAAEAAAAAw==",
+        "AAAAAAAAAAAAAAAQYWNjZXB0X293bmVyc2hpcAAAAAAAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAAAAAAAQcmVtYWluaW5nX3N1cHBseQAAAAEAAAAAAAAADWNvbGxlY3Rpb25faWQAAAAAAAAEAAAAAQAAA+kAAAAEAAAAAw==",
+        "AAAAAAAAAAAAAAARY3JlYXRlX2NvbGxlY3Rpb24AAAAAAAAEAAAAAAAAAAdjcmVhdG9yAAAAABMAAAAAAAAACG1ldGFkYXRhAAAH0AAAAAtORlRNZXRhZGF0YQAAAAAAAAAACm1heF9zdXBwbHkAAAAAAAQAAAAAAAAAB3JveWFsdHkAAAAD6AAAB9AAAAAHUm95YWx0eQAAAAABAAAD6QAAAAQAAAAD",
+        "AAAAAAAAAAAAAAARZ2V0X3BlbmRpbmdfb3duZXIAAAAAAAAAAAAAAQAAA+kAAAPoAAAAEwAAAAM=",
+        "AAAAAAAAAAAAAAATaXNfYXBwcm92ZWRfZm9yX2FsbAAAAAACAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAACG9wZXJhdG9yAAAAEwAAAAEAAAPpAAAAAQAAAAM=",
+        "AAAAAAAAAAAAAAATc2V0X2NvbGxlY3Rpb25faW5mbwAAAAACAAAAAAAAAARuYW1lAAAAEAAAAAAAAAAGc3ltYm9sAAAAAAAQAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAAAAAAATc2V0X2RlZmF1bHRfcm95YWx0eQAAAAABAAAAAAAAAAdyb3lhbHR5AAAAA+gAAAfQAAAAB1JveWFsdHkAAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAAAAAAAZY2FuY2VsX293bmVyc2hpcF90cmFuc2ZlcgAAAAAAAAAAAAABAAAD6QAAA+0AAAAAAAAAAw==" ]),
       options
     )
   }
   public readonly fromJSON = {
-    initialize: this.txFromJSON<null>,
-        mint: this.txFromJSON<u32>,
-        transfer: this.txFromJSON<null>,
-        total_supply: this.txFromJSON<u32>,
-        owner_of: this.txFromJSON<string>,
-        token_metadata: this.txFromJSON<NFTMetadata>,
-        token_uri: this.txFromJSON<SorobanString>,
-        get_admin: this.txFromJSON<string>,
-        balance_of: this.txFromJSON<u32>
+    burn: this.txFromJSON<Result<void>>,
+        mint: this.txFromJSON<Result<u32>>,
+        name: this.txFromJSON<Result<string>>,
+        symbol: this.txFromJSON<Result<string>>,
+        approve: this.txFromJSON<Result<void>>,
+        balance: this.txFromJSON<Result<u32>>,
+        migrate: this.txFromJSON<Result<u32>>,
+        upgrade: this.txFromJSON<Result<void>>,
+        version: this.txFromJSON<Result<u32>>,
+        owner_of: this.txFromJSON<Result<string>>,
+        transfer: this.txFromJSON<Result<void>>,
+        burn_from: this.txFromJSON<Result<void>>,
+        get_admin: this.txFromJSON<Result<string>>,
+        token_uri: this.txFromJSON<Result<string>>,
+        admin_burn: this.txFromJSON<Result<void>>,
+        edition_of: this.txFromJSON<Result<Option<Edition>>>,
+        initialize: this.txFromJSON<Result<void>>,
+        get_approved: this.txFromJSON<Result<Option<string>>>,
+        mint_edition: this.txFromJSON<Result<u32>>,
+        royalty_info: this.txFromJSON<Result<readonly [string, i128]>>,
+        total_burned: this.txFromJSON<Result<u32>>,
+        total_minted: this.txFromJSON<Result<u32>>,
+        total_supply: this.txFromJSON<Result<u32>>,
+        propose_owner: this.txFromJSON<Result<void>>,
+        transfer_from: this.txFromJSON<Result<void>>,
+        get_collection: this.txFromJSON<Result<Collection>>,
+        token_metadata: this.txFromJSON<Result<NFTMetadata>>,
+        approve_for_all: this.txFromJSON<Result<void>>,
+        tokens_of_owner: this.txFromJSON<Result<Array<u32>>>,
+        accept_ownership: this.txFromJSON<Result<void>>,
+        remaining_supply: this.txFromJSON<Result<u32>>,
+        create_collection: this.txFromJSON<Result<u32>>,
+        get_pending_owner: this.txFromJSON<Result<Option<string>>>,
+        is_approved_for_all: this.txFromJSON<Result<boolean>>,
+        set_collection_info: this.txFromJSON<Result<void>>,
+        set_default_royalty: this.txFromJSON<Result<void>>,
+        cancel_ownership_transfer: this.txFromJSON<Result<void>>
   }
 }
\ No newline at end of file
@@ -1,23 +1,117 @@
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, Address, Env, String as SorobanString, Symbol,
+    contract, contracterror, contractimpl, contracttype, Address, BytesN, Env, IntoVal, String,
+    Symbol, TryFromVal, Val, Vec,
 };
 
-#[derive(Clone)]
+// Bumped whenever the storage layout changes; see migrate_from
+const STORAGE_VERSION: u32 = 3;
+
+const MAX_PAGE_SIZE: u32 = 50; // upper bound on tokens_of_owner's `limit`
+const MAX_ROYALTY_BPS: u32 = 5000; // royalties above 50% of a resale are rejected
+const BASIS_POINTS: i128 = 10000;
+
+// Instance storage (admin, counters) is bumped by every entrypoint
+const DAY_IN_LEDGERS: u32 = 17280; // ~5s per ledger
+const INSTANCE_BUMP_AMOUNT: u32 = 7 * DAY_IN_LEDGERS;
+const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+// Per-token data and per-owner indexes are persistent entries, bumped on every read and write
+const PERSISTENT_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
 #[contracttype]
 pub struct NFTMetadata {
-    pub name: SorobanString,
-    pub description: SorobanString,
-    pub image_url: SorobanString,
+    pub name: String,
+    pub description: String,
+    pub image_url: String,
 }
 
 #[derive(Clone)]
 #[contracttype]
 pub enum DataKey {
     Admin,
+    PendingAdmin,
+    Version,
+    MigrationCursor, // next token id for a migration step that walks every token
+    // Persistent; tokens minted by older versions keep them in instance storage until they move
     TokenOwner(u32),
     TokenMetadata(u32),
-    TotalSupply,
+    Name,
+    TokenSymbol,
+    TotalSupply, // v1 mint counter, replaced by TotalMinted in v2
+    TotalMinted,
+    TotalBurned,
+    // Approvals live in temporary storage and lapse with their entries
+    Approval(u32),
+    OperatorApproval(Address /* owner */, Address /* operator */),
+    // Per-owner enumeration, kept in persistent storage
+    OwnerBalance(Address),
+    OwnedToken(Address, u32 /* index */),
+    OwnedTokenIndex(u32 /* token_id */),
+    DefaultRoyalty,
+    TokenRoyalty(u32), // persistent; overrides DefaultRoyalty
+    // Collections and their editions, kept in persistent storage
+    CollectionCount,
+    Collection(u32),
+    CollectionRoyalty(u32), // applies to the collection's editions
+    TokenEdition(u32 /* token_id */),
+}
+
+// A limited run of editions sharing one set of metadata
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Collection {
+    pub creator: Address,
+    pub metadata: NFTMetadata,
+    pub max_supply: u32,
+    pub minted: u32,
+}
+
+// Token `number` of `max_supply` in a collection, e.g. 3 of 100
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Edition {
+    pub collection_id: u32,
+    pub number: u32,
+    pub max_supply: u32,
+}
+
+// Secondary-sale royalty, EIP-2981 style: `bps` of the sale price goes to `receiver`
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Royalty {
+    pub receiver: Address,
+    pub bps: u32,
+}
+
+// Address allowed to transfer one token until `live_until_ledger`
+#[derive(Clone)]
+#[contracttype]
+pub struct ApprovalData {
+    pub approved: Address,
+    pub live_until_ledger: u32,
+}
+
+// Stable error codes exposed to clients; append new variants only
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    TokenNotFound = 3,
+    NotTokenOwner = 4,
+    NoPendingOwner = 5,
+    NotApproved = 6,
+    InvalidLiveUntilLedger = 7,
+    InvalidRoyalty = 8,
+    RoyaltyNotSet = 9,
+    CollectionNotFound = 10,
+    InvalidMaxSupply = 11,
+    CollectionSoldOut = 12,
+    InvalidSalePrice = 13,
 }
 
 #[contract]
@@ -25,39 +119,94 @@ pub struct NftContract;
 
 #[contractimpl]
 impl NftContract {
-    // Initialize the contract with an admin address
-    pub fn initialize(env: Env, admin: Address) {
+    // Initialize the contract with an admin address and the collection's name and symbol
+    pub fn initialize(env: Env, admin: Address, name: String, symbol: String) -> Result<(), Error> {
+        extend_instance(&env);
         // Ensure contract is not already initialized
         if env.storage().instance().has(&DataKey::Admin) {
-            panic!("contract has already been initialized");
+            return Err(Error::AlreadyInitialized);
         }
 
         // Set the admin address
         env.storage().instance().set(&DataKey::Admin, &admin);
+
+        // Collection metadata (SEP-0050)
+        env.storage().instance().set(&DataKey::Name, &name);
+        env.storage().instance().set(&DataKey::TokenSymbol, &symbol);
         
-        // Initialize total supply to 0
-        env.storage().instance().set(&DataKey::TotalSupply, &0u32);
+        // Initialize mint and burn counters to 0
+        env.storage().instance().set(&DataKey::TotalMinted, &0u32);
+        env.storage().instance().set(&DataKey::TotalBurned, &0u32);
+
+        env.storage().instance().set(&DataKey::Version, &STORAGE_VERSION);
+        Ok(())
+    }
+
+    // Replace the contract code in place (admin only); call migrate afterwards
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), Error> {
+        extend_instance(&env);
+        let admin = read_admin(&env)?;
+        admin.require_auth();
+
+        env.deployer().update_current_contract_wasm(new_wasm_hash.clone());
+
+        env.events()
+            .publish(
+                (Symbol::new(&env, "upgraded"),),
+                new_wasm_hash,
+            );
+        Ok(())
+    }
+
+    // Bring stored data up to STORAGE_VERSION, visiting at most `max_tokens` tokens per call,
+    // and return the resulting version; call again until it reports STORAGE_VERSION
+    pub fn migrate(env: Env, max_tokens: u32) -> Result<u32, Error> {
+        extend_instance(&env);
+        let admin = read_admin(&env)?;
+        admin.require_auth();
+
+        let from_version = read_version(&env);
+        let mut version = from_version;
+        let mut budget = max_tokens;
+        while version < STORAGE_VERSION && migrate_from(&env, version, &mut budget) {
+            version += 1;
+        }
+
+        if version != from_version {
+            env.storage().instance().set(&DataKey::Version, &version);
+            env.events()
+                .publish(
+                    (Symbol::new(&env, "migrated"),),
+                    (from_version, version),
+                );
+        }
+        Ok(version)
+    }
+
+    // Get the storage layout version
+    pub fn version(env: Env) -> Result<u32, Error> {
+        extend_instance(&env);
+        Ok(read_version(&env))
     }
 
-    // Mint a new NFT
+    // Mint a new NFT; `royalty` overrides the collection default for this token
     pub fn mint(
         env: Env,
         to: Address,
-        name: SorobanString,
-        description: SorobanString,
-        image_url: SorobanString,
-    ) -> u32 {
+        name: String,
+        description: String,
+        image_url: String,
+        royalty: Option<Royalty>,
+    ) -> Result<u32, Error> {
+        extend_instance(&env);
         // Only admin can mint
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        let admin = read_admin(&env)?;
         admin.require_auth();
+        if let Some(royalty) = &royalty {
+            check_royalty(royalty)?;
+        }
 
-        // Get and update the total supply
-        let mut total_supply: u32 = env.storage().instance().get(&DataKey::TotalSupply).unwrap();
-        total_supply += 1;
-        let token_id = total_supply;
-
-        // Update total supply
-        env.storage().instance().set(&DataKey::TotalSupply, &total_supply);
+        let token_id = mint_token(&env, to);
 
         // Store token metadata
         let metadata = NFTMetadata {
@@ -65,111 +214,740 @@ impl NftContract {
             description,
             image_url,
         };
-        env.storage().instance().set(&DataKey::TokenMetadata(token_id), &metadata);
+        write_persistent(&env, &DataKey::TokenMetadata(token_id), &metadata);
+
+        if let Some(royalty) = royalty {
+            write_persistent(&env, &DataKey::TokenRoyalty(token_id), &royalty);
+        }
+
+        Ok(token_id)
+    }
+
+    // Define a collection of at most `max_supply` editions; returns the collection id
+    pub fn create_collection(
+        env: Env,
+        creator: Address,
+        metadata: NFTMetadata,
+        max_supply: u32,
+        royalty: Option<Royalty>,
+    ) -> Result<u32, Error> {
+        extend_instance(&env);
+        read_admin(&env)?;
+        creator.require_auth();
+        if max_supply == 0 {
+            return Err(Error::InvalidMaxSupply);
+        }
+        if let Some(royalty) = &royalty {
+            check_royalty(royalty)?;
+        }
 
-        // Set token owner
-        env.storage().instance().set(&DataKey::TokenOwner(token_id), &to);
+        let collection_id = read_counter(&env, DataKey::CollectionCount) + 1;
+        env.storage().instance().set(&DataKey::CollectionCount, &collection_id);
+        let collection = Collection {
+            creator: creator.clone(),
+            metadata,
+            max_supply,
+            minted: 0,
+        };
+        write_persistent(&env, &DataKey::Collection(collection_id), &collection);
+        if let Some(royalty) = royalty {
+            write_persistent(&env, &DataKey::CollectionRoyalty(collection_id), &royalty);
+        }
 
-        // Emit mint event
         env.events()
             .publish(
-                (Symbol::new(&env, "mint"), to),
-                token_id,
+                (Symbol::new(&env, "collection_created"), creator),
+                (collection_id, max_supply),
             );
-
-        token_id
+        Ok(collection_id)
     }
 
-    // Transfer NFT to another address
-    pub fn transfer(env: Env, from: Address, to: Address, token_id: u32) {
-        // Verify token exists
-        if !env.storage().instance().has(&DataKey::TokenOwner(token_id)) {
-            panic!("token does not exist");
+    // Mint the next numbered edition of a collection (admin only)
+    pub fn mint_edition(env: Env, to: Address, collection_id: u32) -> Result<u32, Error> {
+        extend_instance(&env);
+        let admin = read_admin(&env)?;
+        admin.require_auth();
+
+        let mut collection = read_collection(&env, collection_id)?;
+        if collection.minted >= collection.max_supply {
+            return Err(Error::CollectionSoldOut);
         }
+        collection.minted += 1;
+        write_persistent(&env, &DataKey::Collection(collection_id), &collection);
+
+        let token_id = mint_token(&env, to);
+        let edition = Edition {
+            collection_id,
+            number: collection.minted,
+            max_supply: collection.max_supply,
+        };
+        write_persistent(&env, &DataKey::TokenEdition(token_id), &edition);
+        Ok(token_id)
+    }
+
+    // Get a collection, including its creator and how many editions were minted
+    pub fn get_collection(env: Env, collection_id: u32) -> Result<Collection, Error> {
+        extend_instance(&env);
+        read_collection(&env, collection_id)
+    }
 
-        // Verify that the sender is the current owner
-        let owner: Address = env.storage().instance().get(&DataKey::TokenOwner(token_id)).unwrap();
+    // Editions of a collection that can still be minted
+    pub fn remaining_supply(env: Env, collection_id: u32) -> Result<u32, Error> {
+        extend_instance(&env);
+        let collection = read_collection(&env, collection_id)?;
+        Ok(collection.max_supply - collection.minted)
+    }
+
+    // Collection and edition number of a token; None for tokens minted outside a collection
+    pub fn edition_of(env: Env, token_id: u32) -> Result<Option<Edition>, Error> {
+        extend_instance(&env);
+        read_token_owner(&env, token_id)?;
+        Ok(read_persistent(&env, &DataKey::TokenEdition(token_id)))
+    }
+
+    // Transfer NFT to another address
+    pub fn transfer(env: Env, from: Address, to: Address, token_id: u32) -> Result<(), Error> {
+        extend_instance(&env);
+        // Verify token exists and that the sender is the current owner
+        let owner = read_token_owner(&env, token_id)?;
         if owner != from {
-            panic!("sender is not the token owner");
+            return Err(Error::NotTokenOwner);
         }
 
         // Require authorization from the sender
         from.require_auth();
 
-        // Update token owner
-        env.storage().instance().set(&DataKey::TokenOwner(token_id), &to);
+        move_token(&env, from, to, token_id);
+        Ok(())
+    }
+
+    // Transfer on behalf of `from` by an approved address or operator
+    pub fn transfer_from(
+        env: Env,
+        spender: Address,
+        from: Address,
+        to: Address,
+        token_id: u32,
+    ) -> Result<(), Error> {
+        extend_instance(&env);
+        let owner = read_token_owner(&env, token_id)?;
+        if owner != from {
+            return Err(Error::NotTokenOwner);
+        }
+
+        spender.require_auth();
+        if spender != from
+            && read_approved(&env, token_id) != Some(spender.clone())
+            && !is_operator(&env, &from, &spender)
+        {
+            return Err(Error::NotApproved);
+        }
+
+        move_token(&env, from, to, token_id);
+        Ok(())
+    }
+
+    // Let `approved` transfer one token until `live_until_ledger`; a ledger of 0 revokes.
+    // The approver is the token owner or one of the owner's operators.
+    pub fn approve(
+        env: Env,
+        approver: Address,
+        approved: Address,
+        token_id: u32,
+        live_until_ledger: u32,
+    ) -> Result<(), Error> {
+        extend_instance(&env);
+        let owner = read_token_owner(&env, token_id)?;
+        approver.require_auth();
+        if approver != owner && !is_operator(&env, &owner, &approver) {
+            return Err(Error::NotTokenOwner);
+        }
+
+        let key = DataKey::Approval(token_id);
+        if live_until_ledger == 0 {
+            env.storage().temporary().remove(&key);
+        } else {
+            let ttl = ttl_until(&env, live_until_ledger)?;
+            let approval = ApprovalData { approved: approved.clone(), live_until_ledger };
+            env.storage().temporary().set(&key, &approval);
+            env.storage().temporary().extend_ttl(&key, ttl, ttl);
+        }
 
-        // Emit transfer event
         env.events()
             .publish(
-                (Symbol::new(&env, "transfer"), from, to),
-                token_id,
+                (Symbol::new(&env, "approve"), owner, token_id),
+                (approved, live_until_ledger),
             );
+        Ok(())
     }
 
-    // Get the total supply of NFTs
-    pub fn total_supply(env: Env) -> u32 {
-        env.storage().instance().get(&DataKey::TotalSupply).unwrap_or(0u32)
+    // Address approved for a token, if the approval has not expired
+    pub fn get_approved(env: Env, token_id: u32) -> Result<Option<Address>, Error> {
+        extend_instance(&env);
+        read_token_owner(&env, token_id)?;
+        Ok(read_approved(&env, token_id))
     }
 
-    // Get the owner of a token by ID
-    pub fn owner_of(env: Env, token_id: u32) -> Address {
-        if !env.storage().instance().has(&DataKey::TokenOwner(token_id)) {
-            panic!("token does not exist");
+    // Let `operator` transfer and approve any of the owner's tokens until `live_until_ledger`;
+    // a ledger of 0 revokes
+    pub fn approve_for_all(
+        env: Env,
+        owner: Address,
+        operator: Address,
+        live_until_ledger: u32,
+    ) -> Result<(), Error> {
+        extend_instance(&env);
+        owner.require_auth();
+
+        let key = DataKey::OperatorApproval(owner.clone(), operator.clone());
+        if live_until_ledger == 0 {
+            env.storage().temporary().remove(&key);
+        } else {
+            let ttl = ttl_until(&env, live_until_ledger)?;
+            env.storage().temporary().set(&key, &live_until_ledger);
+            env.storage().temporary().extend_ttl(&key, ttl, ttl);
+        }
+
+        env.events()
+            .publish(
+                (Symbol::new(&env, "approve_for_all"), owner),
+                (operator, live_until_ledger),
+            );
+        Ok(())
+    }
+
+    // Whether `operator` may currently act for all of the owner's tokens
+    pub fn is_approved_for_all(env: Env, owner: Address, operator: Address) -> Result<bool, Error> {
+        extend_instance(&env);
+        Ok(is_operator(&env, &owner, &operator))
+    }
+
+    // Destroy a token; called by its holder
+    pub fn burn(env: Env, from: Address, token_id: u32) -> Result<(), Error> {
+        extend_instance(&env);
+        let owner = read_token_owner(&env, token_id)?;
+        if owner != from {
+            return Err(Error::NotTokenOwner);
         }
-        env.storage().instance().get(&DataKey::TokenOwner(token_id)).unwrap()
+
+        from.require_auth();
+
+        destroy_token(&env, from, token_id);
+        Ok(())
+    }
+
+    // Destroy a token on behalf of `from` by an approved address or operator
+    pub fn burn_from(
+        env: Env,
+        spender: Address,
+        from: Address,
+        token_id: u32,
+    ) -> Result<(), Error> {
+        extend_instance(&env);
+        let owner = read_token_owner(&env, token_id)?;
+        if owner != from {
+            return Err(Error::NotTokenOwner);
+        }
+
+        spender.require_auth();
+        if spender != from
+            && read_approved(&env, token_id) != Some(spender.clone())
+            && !is_operator(&env, &from, &spender)
+        {
+            return Err(Error::NotApproved);
+        }
+
+        destroy_token(&env, from, token_id);
+        Ok(())
+    }
+
+    // Forced burn by the admin, e.g. to revoke a pass after a terms-of-service removal
+    pub fn admin_burn(env: Env, token_id: u32) -> Result<(), Error> {
+        extend_instance(&env);
+        let admin = read_admin(&env)?;
+        admin.require_auth();
+
+        let owner = read_token_owner(&env, token_id)?;
+        destroy_token(&env, owner, token_id);
+        Ok(())
+    }
+
+    // Get the number of NFTs in existence (minted minus burned)
+    pub fn total_supply(env: Env) -> Result<u32, Error> {
+        extend_instance(&env);
+        Ok(read_total_minted(&env) - read_counter(&env, DataKey::TotalBurned))
+    }
+
+    // Get the number of NFTs ever minted
+    pub fn total_minted(env: Env) -> Result<u32, Error> {
+        extend_instance(&env);
+        Ok(read_total_minted(&env))
+    }
+
+    // Get the number of NFTs burned so far
+    pub fn total_burned(env: Env) -> Result<u32, Error> {
+        extend_instance(&env);
+        Ok(read_counter(&env, DataKey::TotalBurned))
+    }
+
+    // Get the owner of a token by ID
+    pub fn owner_of(env: Env, token_id: u32) -> Result<Address, Error> {
+        extend_instance(&env);
+        read_token_owner(&env, token_id)
     }
 
     // Get the metadata of a token by ID
-    pub fn token_metadata(env: Env, token_id: u32) -> NFTMetadata {
-        if !env.storage().instance().has(&DataKey::TokenMetadata(token_id)) {
-            panic!("token does not exist");
+    pub fn token_metadata(env: Env, token_id: u32) -> Result<NFTMetadata, Error> {
+        extend_instance(&env);
+        read_token_metadata(&env, token_id)
+    }
+
+    // Royalty for tokens minted without their own; None removes it
+    pub fn set_default_royalty(env: Env, royalty: Option<Royalty>) -> Result<(), Error> {
+        extend_instance(&env);
+        let admin = read_admin(&env)?;
+        admin.require_auth();
+
+        match &royalty {
+            Some(royalty) => {
+                check_royalty(royalty)?;
+                env.storage().instance().set(&DataKey::DefaultRoyalty, royalty);
+            }
+            None => env.storage().instance().remove(&DataKey::DefaultRoyalty),
         }
-        env.storage().instance().get(&DataKey::TokenMetadata(token_id)).unwrap()
+
+        env.events()
+            .publish(
+                (Symbol::new(&env, "default_royalty"),),
+                royalty,
+            );
+        Ok(())
     }
 
-    // Get the URI of a token (which is just the image_url in this case)
-    pub fn token_uri(env: Env, token_id: u32) -> SorobanString {
-        if !env.storage().instance().has(&DataKey::TokenMetadata(token_id)) {
-            panic!("token does not exist");
+    // Receiver and amount owed on a resale of `token_id` for `sale_price` (EIP-2981 style)
+    pub fn royalty_info(
+        env: Env,
+        token_id: u32,
+        sale_price: i128,
+    ) -> Result<(Address, i128), Error> {
+        extend_instance(&env);
+        if sale_price < 0 {
+            return Err(Error::InvalidSalePrice);
         }
-        let metadata: NFTMetadata = env.storage().instance().get(&DataKey::TokenMetadata(token_id)).unwrap();
-        metadata.image_url
+        read_token_owner(&env, token_id)?;
+        // Token royalty first, then the token's collection, then the contract default
+        let royalty: Royalty = read_persistent(&env, &DataKey::TokenRoyalty(token_id))
+            .or_else(|| {
+                let edition: Edition = read_persistent(&env, &DataKey::TokenEdition(token_id))?;
+                read_persistent(&env, &DataKey::CollectionRoyalty(edition.collection_id))
+            })
+            .or_else(|| env.storage().instance().get(&DataKey::DefaultRoyalty))
+            .ok_or(Error::RoyaltyNotSet)?;
+        let amount = sale_price * royalty.bps as i128 / BASIS_POINTS;
+        Ok((royalty.receiver, amount))
+    }
+
+    // Get the collection name
+    pub fn name(env: Env) -> Result<String, Error> {
+        extend_instance(&env);
+        env.storage().instance().get(&DataKey::Name).ok_or(Error::NotInitialized)
+    }
+
+    // Get the collection symbol
+    pub fn symbol(env: Env) -> Result<String, Error> {
+        extend_instance(&env);
+        env.storage().instance().get(&DataKey::TokenSymbol).ok_or(Error::NotInitialized)
+    }
+
+    // Replace the collection name and symbol (admin only); contracts upgraded from
+    // before SEP-0050 metadata have neither until this is called
+    pub fn set_collection_info(env: Env, name: String, symbol: String) -> Result<(), Error> {
+        extend_instance(&env);
+        let admin = read_admin(&env)?;
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::Name, &name);
+        env.storage().instance().set(&DataKey::TokenSymbol, &symbol);
+
+        env.events()
+            .publish(
+                (Symbol::new(&env, "collection_info"),),
+                (name, symbol),
+            );
+        Ok(())
+    }
+
+    // Get the URI of a token (which is just the image_url in this case)
+    pub fn token_uri(env: Env, token_id: u32) -> Result<String, Error> {
+        extend_instance(&env);
+        Ok(read_token_metadata(&env, token_id)?.image_url)
     }
 
     // Get the admin address
-    pub fn get_admin(env: Env) -> Address {
-        env.storage().instance().get(&DataKey::Admin).unwrap()
+    pub fn get_admin(env: Env) -> Result<Address, Error> {
+        extend_instance(&env);
+        read_admin(&env)
+    }
+
+    // Get the admin address proposed by propose_owner, if any
+    pub fn get_pending_owner(env: Env) -> Result<Option<Address>, Error> {
+        extend_instance(&env);
+        Ok(env.storage().instance().get(&DataKey::PendingAdmin))
+    }
+
+    // Propose a new admin; it takes over only after calling accept_ownership
+    pub fn propose_owner(env: Env, new_admin: Address) -> Result<(), Error> {
+        extend_instance(&env);
+        let admin = read_admin(&env)?;
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::PendingAdmin, &new_admin);
+
+        env.events()
+            .publish(
+                (Symbol::new(&env, "ownership_proposed"), admin),
+                new_admin,
+            );
+        Ok(())
+    }
+
+    // Accept a pending proposal; requires the proposed admin's signature
+    pub fn accept_ownership(env: Env) -> Result<(), Error> {
+        extend_instance(&env);
+        let old_admin = read_admin(&env)?;
+        let new_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::PendingAdmin)
+            .ok_or(Error::NoPendingOwner)?;
+        new_admin.require_auth();
+
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+        env.storage().instance().remove(&DataKey::PendingAdmin);
+
+        env.events()
+            .publish(
+                (Symbol::new(&env, "ownership_transferred"), old_admin),
+                new_admin,
+            );
+        Ok(())
+    }
+
+    // Withdraw a pending proposal
+    pub fn cancel_ownership_transfer(env: Env) -> Result<(), Error> {
+        extend_instance(&env);
+        let admin = read_admin(&env)?;
+        admin.require_auth();
+
+        let pending: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::PendingAdmin)
+            .ok_or(Error::NoPendingOwner)?;
+        env.storage().instance().remove(&DataKey::PendingAdmin);
+
+        env.events()
+            .publish(
+                (Symbol::new(&env, "ownership_cancelled"), admin),
+                pending,
+            );
+        Ok(())
     }
 
     // Get the balance (number of NFTs) owned by an address
-    pub fn balance_of(env: Env, owner: Address) -> u32 {
-        let total_supply: u32 = env.storage().instance().get(&DataKey::TotalSupply).unwrap_or(0);
-        let mut balance: u32 = 0;
-        
-        // Iterate through all tokens and count those owned by the address
-        for token_id in 1..=total_supply {
-            if env.storage().instance().has(&DataKey::TokenOwner(token_id)) {
-                let token_owner: Address = env.storage().instance().get(&DataKey::TokenOwner(token_id)).unwrap();
-                if token_owner == owner {
-                    balance += 1;
+    pub fn balance(env: Env, owner: Address) -> Result<u32, Error> {
+        extend_instance(&env);
+        Ok(read_owner_balance(&env, &owner))
+    }
+
+    // Page through an owner's token ids; order is not stable across transfers and burns
+    pub fn tokens_of_owner(
+        env: Env,
+        owner: Address,
+        start: u32,
+        limit: u32,
+    ) -> Result<Vec<u32>, Error> {
+        extend_instance(&env);
+        let balance = read_owner_balance(&env, &owner);
+        let end = balance.min(start.saturating_add(limit.min(MAX_PAGE_SIZE)));
+        let mut tokens = Vec::new(&env);
+        for index in start..end {
+            let token_id: u32 =
+                read_persistent(&env, &DataKey::OwnedToken(owner.clone(), index)).unwrap();
+            tokens.push_back(token_id);
+        }
+        Ok(tokens)
+    }
+}
+
+// A missing version means the contract predates versioning
+fn read_version(env: &Env) -> u32 {
+    env.storage().instance().get(&DataKey::Version).unwrap_or(0)
+}
+
+// One migration step: upgrade data stored at `version` to `version + 1`.
+// Steps that walk tokens spend `budget` and return false to resume on the next call.
+fn migrate_from(env: &Env, version: u32, budget: &mut u32) -> bool {
+    match version {
+        // Nothing to move; version 1 only introduced the version key
+        0 => {}
+        // Version 2 split the mint counter into minted and burned counts
+        1 => {
+            let minted: Option<u32> = env.storage().instance().get(&DataKey::TotalSupply);
+            if let Some(minted) = minted {
+                env.storage().instance().set(&DataKey::TotalMinted, &minted);
+                env.storage().instance().remove(&DataKey::TotalSupply);
+            }
+            if !env.storage().instance().has(&DataKey::TotalBurned) {
+                env.storage().instance().set(&DataKey::TotalBurned, &0u32);
+            }
+        }
+        // Version 3 added per-owner indexes; build them from the existing owners.
+        // Tokens minted or moved since the upgrade are already indexed.
+        2 => {
+            let minted = read_counter(env, DataKey::TotalMinted);
+            let mut token_id = read_counter(env, DataKey::MigrationCursor).max(1);
+            while token_id <= minted {
+                if *budget == 0 {
+                    env.storage().instance().set(&DataKey::MigrationCursor, &token_id);
+                    return false;
+                }
+                *budget -= 1;
+                if !env.storage().persistent().has(&DataKey::OwnedTokenIndex(token_id)) {
+                    if let Ok(owner) = read_token_owner(env, token_id) {
+                        add_to_owner(env, &owner, token_id);
+                    }
                 }
+                token_id += 1;
             }
+            env.storage().instance().remove(&DataKey::MigrationCursor);
         }
-        
-        balance
+        _ => unreachable!(),
+    }
+    true
+}
+
+fn read_counter(env: &Env, key: DataKey) -> u32 {
+    env.storage().instance().get(&key).unwrap_or(0)
+}
+
+// Upgraded contracts keep counting in the version 1 counter until they are migrated
+fn read_total_minted(env: &Env) -> u32 {
+    let storage = env.storage().instance();
+    storage
+        .get(&DataKey::TotalMinted)
+        .or_else(|| storage.get(&DataKey::TotalSupply))
+        .unwrap_or(0)
+}
+
+fn read_admin(env: &Env) -> Result<Address, Error> {
+    env.storage().instance().get(&DataKey::Admin).ok_or(Error::NotInitialized)
+}
+
+fn read_token_owner(env: &Env, token_id: u32) -> Result<Address, Error> {
+    let key = DataKey::TokenOwner(token_id);
+    read_persistent(env, &key)
+        .or_else(|| env.storage().instance().get(&key))
+        .ok_or(Error::TokenNotFound)
+}
+
+// Tokens still held in instance storage move to persistent storage when they change hands
+fn write_token_owner(env: &Env, token_id: u32, owner: &Address) {
+    write_persistent(env, &DataKey::TokenOwner(token_id), owner);
+    let instance = env.storage().instance();
+    if instance.has(&DataKey::TokenOwner(token_id)) {
+        instance.remove(&DataKey::TokenOwner(token_id));
+        let metadata: Option<NFTMetadata> = instance.get(&DataKey::TokenMetadata(token_id));
+        if let Some(metadata) = metadata {
+            write_persistent(env, &DataKey::TokenMetadata(token_id), &metadata);
+            instance.remove(&DataKey::TokenMetadata(token_id));
+        }
+    }
+}
+
+// Change ownership, drop any per-token approval and emit the transfer event
+fn move_token(env: &Env, from: Address, to: Address, token_id: u32) {
+    write_token_owner(env, token_id, &to);
+    remove_from_owner(env, &from, token_id);
+    add_to_owner(env, &to, token_id);
+    env.storage().temporary().remove(&DataKey::Approval(token_id));
+
+    env.events()
+        .publish(
+            (Symbol::new(env, "transfer"), from, to),
+            token_id,
+        );
+}
+
+// Remove a token with its metadata and approval, and count the burn
+fn destroy_token(env: &Env, from: Address, token_id: u32) {
+    for key in [DataKey::TokenOwner(token_id), DataKey::TokenMetadata(token_id)] {
+        env.storage().persistent().remove(&key);
+        env.storage().instance().remove(&key);
+    }
+    remove_from_owner(env, &from, token_id);
+    env.storage().persistent().remove(&DataKey::TokenRoyalty(token_id));
+    env.storage().persistent().remove(&DataKey::TokenEdition(token_id));
+    env.storage().temporary().remove(&DataKey::Approval(token_id));
+    let burned = read_counter(env, DataKey::TotalBurned) + 1;
+    env.storage().instance().set(&DataKey::TotalBurned, &burned);
+
+    env.events()
+        .publish(
+            (Symbol::new(env, "burn"), from),
+            token_id,
+        );
+}
+
+fn read_owner_balance(env: &Env, owner: &Address) -> u32 {
+    let key = DataKey::OwnerBalance(owner.clone());
+    let balance = env.storage().persistent().get(&key).unwrap_or(0);
+    if balance > 0 {
+        extend_persistent(env, &key);
+    }
+    balance
+}
+
+// Append a token to the owner's index
+fn add_to_owner(env: &Env, owner: &Address, token_id: u32) {
+    let index = read_owner_balance(env, owner);
+    write_persistent(env, &DataKey::OwnedToken(owner.clone(), index), &token_id);
+    write_persistent(env, &DataKey::OwnedTokenIndex(token_id), &index);
+    write_persistent(env, &DataKey::OwnerBalance(owner.clone()), &(index + 1));
+}
+
+// Drop a token from the owner's index by moving the owner's last token into its slot.
+// Tokens the version 3 migration hasn't reached yet have no index entry to drop.
+fn remove_from_owner(env: &Env, owner: &Address, token_id: u32) {
+    let storage = env.storage().persistent();
+    let index: u32 = match storage.get(&DataKey::OwnedTokenIndex(token_id)) {
+        Some(index) => index,
+        None => return,
+    };
+    let last_index = read_owner_balance(env, owner) - 1;
+    if index != last_index {
+        let last_token: u32 = storage.get(&DataKey::OwnedToken(owner.clone(), last_index)).unwrap();
+        write_persistent(env, &DataKey::OwnedToken(owner.clone(), index), &last_token);
+        write_persistent(env, &DataKey::OwnedTokenIndex(last_token), &index);
+    }
+    storage.remove(&DataKey::OwnedToken(owner.clone(), last_index));
+    storage.remove(&DataKey::OwnedTokenIndex(token_id));
+    if last_index == 0 {
+        storage.remove(&DataKey::OwnerBalance(owner.clone()));
+    } else {
+        write_persistent(env, &DataKey::OwnerBalance(owner.clone()), &last_index);
+    }
+}
+
+fn read_persistent<V: TryFromVal<Env, Val>>(env: &Env, key: &DataKey) -> Option<V> {
+    let value = env.storage().persistent().get(key);
+    if value.is_some() {
+        extend_persistent(env, key);
+    }
+    value
+}
+
+fn write_persistent<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+    env.storage().persistent().set(key, value);
+    extend_persistent(env, key);
+}
+
+fn extend_instance(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
+
+fn extend_persistent(env: &Env, key: &DataKey) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+// Temporary entries can outlive their expiry ledger, so the ledger is checked on read
+fn read_approved(env: &Env, token_id: u32) -> Option<Address> {
+    let approval: ApprovalData = env.storage().temporary().get(&DataKey::Approval(token_id))?;
+    (approval.live_until_ledger >= env.ledger().sequence()).then_some(approval.approved)
+}
+
+fn is_operator(env: &Env, owner: &Address, operator: &Address) -> bool {
+    let key = DataKey::OperatorApproval(owner.clone(), operator.clone());
+    let live_until: Option<u32> = env.storage().temporary().get(&key);
+    live_until.is_some_and(|ledger| ledger >= env.ledger().sequence())
+}
+
+// TTL that keeps an approval entry around until `live_until_ledger`
+fn ttl_until(env: &Env, live_until_ledger: u32) -> Result<u32, Error> {
+    let current = env.ledger().sequence();
+    if live_until_ledger < current || live_until_ledger > env.ledger().max_live_until_ledger() {
+        return Err(Error::InvalidLiveUntilLedger);
+    }
+    Ok(live_until_ledger - current)
+}
+
+fn check_royalty(royalty: &Royalty) -> Result<(), Error> {
+    if royalty.bps > MAX_ROYALTY_BPS {
+        return Err(Error::InvalidRoyalty);
+    }
+    Ok(())
+}
+
+// Editions have no metadata of their own and share their collection's
+fn read_token_metadata(env: &Env, token_id: u32) -> Result<NFTMetadata, Error> {
+    let key = DataKey::TokenMetadata(token_id);
+    let metadata = read_persistent(env, &key).or_else(|| env.storage().instance().get(&key));
+    if let Some(metadata) = metadata {
+        return Ok(metadata);
     }
+    let edition: Edition =
+        read_persistent(env, &DataKey::TokenEdition(token_id)).ok_or(Error::TokenNotFound)?;
+    Ok(read_collection(env, edition.collection_id)?.metadata)
+}
+
+fn read_collection(env: &Env, collection_id: u32) -> Result<Collection, Error> {
+    read_persistent(env, &DataKey::Collection(collection_id)).ok_or(Error::CollectionNotFound)
+}
+
+// Assign the next token id to `to` and emit the mint event
+fn mint_token(env: &Env, to: Address) -> u32 {
+    // Token ids are never reused, so the next id follows the mint count
+    let token_id = read_total_minted(env) + 1;
+    env.storage().instance().set(&DataKey::TotalMinted, &token_id);
+    env.storage().instance().remove(&DataKey::TotalSupply);
+
+    // Set token owner
+    write_persistent(env, &DataKey::TokenOwner(token_id), &to);
+    add_to_owner(env, &to, token_id);
+
+    // Emit mint event
+    env.events()
+        .publish(
+            (Symbol::new(env, "mint"), to),
+            token_id,
+        );
+    token_id
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use soroban_sdk::{testutils::Address as _, Env};
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger},
+        Env,
+    };
+
+    fn name_of(env: &Env) -> String {
+        String::from_str(env, "OnlyFrens Access")
+    }
+
+    fn symbol_of(env: &Env) -> String {
+        String::from_str(env, "FRENS")
+    }
 
     #[test]
     fn test_nft_contract() {
         let env = Env::default();
-        let contract_id = env.register_contract(None, NftContract);
+        let contract_id = env.register(NftContract, ());
         let client = NftContractClient::new(&env, &contract_id);
 
         // Test accounts
@@ -178,17 +956,20 @@ mod test {
         let user2 = Address::generate(&env);
 
         // Initialize the contract
-        client.initialize(&admin);
+        client.initialize(&admin, &name_of(&env), &symbol_of(&env));
         assert_eq!(client.get_admin(), admin);
         assert_eq!(client.total_supply(), 0);
+        assert_eq!(client.name(), name_of(&env));
+        assert_eq!(client.symbol(), symbol_of(&env));
 
         // Mint an NFT
         env.mock_all_auths();
         let token_id = client.mint(
             &user1,
-            &SorobanString::from_str(&env, "Cool NFT"),
-            &SorobanString::from_str(&env, "A very cool NFT for testing"),
-            &SorobanString::from_str(&env, "https://example.com/nft.png"),
+            &String::from_str(&env, "Cool NFT"),
+            &String::from_str(&env, "A very cool NFT for testing"),
+            &String::from_str(&env, "https://example.com/nft.png"),
+            &None,
         );
         assert_eq!(token_id, 1);
         assert_eq!(client.total_supply(), 1);
@@ -196,17 +977,17 @@ mod test {
 
         // Check metadata
         let metadata = client.token_metadata(&token_id);
-        assert_eq!(metadata.name, SorobanString::from_str(&env, "Cool NFT"));
-        assert_eq!(metadata.description, SorobanString::from_str(&env, "A very cool NFT for testing"));
-        assert_eq!(metadata.image_url, SorobanString::from_str(&env, "https://example.com/nft.png"));
+        assert_eq!(metadata.name, String::from_str(&env, "Cool NFT"));
+        assert_eq!(metadata.description, String::from_str(&env, "A very cool NFT for testing"));
+        assert_eq!(metadata.image_url, String::from_str(&env, "https://example.com/nft.png"));
 
         // Check URI
         let uri = client.token_uri(&token_id);
-        assert_eq!(uri, SorobanString::from_str(&env, "https://example.com/nft.png"));
+        assert_eq!(uri, String::from_str(&env, "https://example.com/nft.png"));
 
         // Check balance
-        assert_eq!(client.balance_of(&user1), 1);
-        assert_eq!(client.balance_of(&user2), 0);
+        assert_eq!(client.balance(&user1), 1);
+        assert_eq!(client.balance(&user2), 0);
 
         // Transfer NFT
         env.mock_all_auths();
@@ -214,22 +995,383 @@ mod test {
         assert_eq!(client.owner_of(&token_id), user2);
         
         // Check balance after transfer
-        assert_eq!(client.balance_of(&user1), 0);
-        assert_eq!(client.balance_of(&user2), 1);
+        assert_eq!(client.balance(&user1), 0);
+        assert_eq!(client.balance(&user2), 1);
 
         // Mint another NFT
         env.mock_all_auths();
         let token_id2 = client.mint(
             &user2,
-            &SorobanString::from_str(&env, "Second NFT"),
-            &SorobanString::from_str(&env, "Another cool NFT"),
-            &SorobanString::from_str(&env, "https://example.com/nft2.png"),
+            &String::from_str(&env, "Second NFT"),
+            &String::from_str(&env, "Another cool NFT"),
+            &String::from_str(&env, "https://example.com/nft2.png"),
+            &None,
         );
         assert_eq!(token_id2, 2);
         assert_eq!(client.total_supply(), 2);
         assert_eq!(client.owner_of(&token_id2), user2);
         
         // Check user2 balance after second mint
-        assert_eq!(client.balance_of(&user2), 2);
+        assert_eq!(client.balance(&user2), 2);
+    }
+
+    #[test]
+    fn test_errors_are_typed() {
+        let env = Env::default();
+        let contract_id = env.register(NftContract, ());
+        let client = NftContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let user1 = Address::generate(&env);
+        let user2 = Address::generate(&env);
+
+        assert_eq!(client.try_get_admin(), Err(Ok(Error::NotInitialized)));
+        client.initialize(&admin, &name_of(&env), &symbol_of(&env));
+        assert_eq!(
+            client.try_initialize(&admin, &name_of(&env), &symbol_of(&env)),
+            Err(Ok(Error::AlreadyInitialized))
+        );
+        assert_eq!(client.try_owner_of(&1), Err(Ok(Error::TokenNotFound)));
+        assert_eq!(client.try_token_uri(&1), Err(Ok(Error::TokenNotFound)));
+
+        env.mock_all_auths();
+        let token_id = client.mint(
+            &user1,
+            &String::from_str(&env, "Cool NFT"),
+            &String::from_str(&env, "A very cool NFT for testing"),
+            &String::from_str(&env, "https://example.com/nft.png"),
+            &None,
+        );
+        assert_eq!(
+            client.try_transfer(&user2, &user1, &token_id),
+            Err(Ok(Error::NotTokenOwner))
+        );
+    }
+
+    #[test]
+    fn test_two_step_ownership_transfer() {
+        let env = Env::default();
+        let contract_id = env.register(NftContract, ());
+        let client = NftContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let new_admin = Address::generate(&env);
+        client.initialize(&admin, &name_of(&env), &symbol_of(&env));
+
+        env.mock_all_auths();
+        assert_eq!(client.try_accept_ownership(), Err(Ok(Error::NoPendingOwner)));
+
+        // A cancelled proposal cannot be accepted
+        client.propose_owner(&new_admin);
+        client.cancel_ownership_transfer();
+        assert_eq!(client.get_pending_owner(), None);
+        assert_eq!(client.try_accept_ownership(), Err(Ok(Error::NoPendingOwner)));
+
+        client.propose_owner(&new_admin);
+        assert_eq!(client.get_pending_owner(), Some(new_admin.clone()));
+        assert_eq!(client.get_admin(), admin);
+        client.accept_ownership();
+        assert_eq!(env.auths()[0].0, new_admin);
+        assert_eq!(client.get_admin(), new_admin);
+        assert_eq!(client.get_pending_owner(), None);
+    }
+
+    #[test]
+    fn test_migrate_stamps_storage_version() {
+        let env = Env::default();
+        let contract_id = env.register(NftContract, ());
+        let client = NftContractClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env), &name_of(&env), &symbol_of(&env));
+        env.mock_all_auths();
+
+        assert_eq!(client.version(), STORAGE_VERSION);
+        assert_eq!(client.migrate(&u32::MAX), STORAGE_VERSION);
+
+        // Deployments from before versioning have no stored version
+        env.as_contract(&contract_id, || env.storage().instance().remove(&DataKey::Version));
+        assert_eq!(client.version(), 0);
+        assert_eq!(client.migrate(&u32::MAX), STORAGE_VERSION);
+        assert_eq!(client.version(), STORAGE_VERSION);
+
+        // Nor a name or symbol; the admin sets them after upgrading
+        env.as_contract(&contract_id, || {
+            env.storage().instance().remove(&DataKey::Name);
+            env.storage().instance().remove(&DataKey::TokenSymbol);
+        });
+        assert_eq!(client.try_name(), Err(Ok(Error::NotInitialized)));
+        client.set_collection_info(&name_of(&env), &symbol_of(&env));
+        assert_eq!(client.name(), name_of(&env));
+        assert_eq!(client.symbol(), symbol_of(&env));
+    }
+
+    #[test]
+    fn test_approvals_allow_transfer_from() {
+        let env = Env::default();
+        let contract_id = env.register(NftContract, ());
+        let client = NftContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let user1 = Address::generate(&env);
+        let user2 = Address::generate(&env);
+        let market = Address::generate(&env);
+        client.initialize(&admin, &name_of(&env), &symbol_of(&env));
+
+        env.mock_all_auths();
+        let name = String::from_str(&env, "Cool NFT");
+        let token_id = client.mint(&user1, &name, &name, &name, &None);
+        assert_eq!(
+            client.try_transfer_from(&market, &user1, &user2, &token_id),
+            Err(Ok(Error::NotApproved))
+        );
+        assert_eq!(
+            client.try_approve(&market, &market, &token_id, &100),
+            Err(Ok(Error::NotTokenOwner))
+        );
+
+        // Per-token approval is cleared by the transfer it enables
+        client.approve(&user1, &market, &token_id, &100);
+        assert_eq!(client.get_approved(&token_id), Some(market.clone()));
+        client.transfer_from(&market, &user1, &user2, &token_id);
+        assert_eq!(client.owner_of(&token_id), user2);
+        assert_eq!(client.get_approved(&token_id), None);
+
+        // Operator approval covers every token of the owner until it expires
+        client.approve_for_all(&user2, &market, &100);
+        assert!(client.is_approved_for_all(&user2, &market));
+        client.transfer_from(&market, &user2, &user1, &token_id);
+        assert_eq!(client.owner_of(&token_id), user1);
+        assert!(!client.is_approved_for_all(&user1, &market));
+
+        // A zero ledger revokes either kind of approval
+        client.approve_for_all(&user2, &market, &0);
+        assert!(!client.is_approved_for_all(&user2, &market));
+        client.approve(&user1, &market, &token_id, &100);
+        client.approve(&user1, &market, &token_id, &0);
+        assert_eq!(client.get_approved(&token_id), None);
+
+        client.approve(&user1, &market, &token_id, &100);
+        env.ledger().with_mut(|l| l.sequence_number = 101);
+        assert_eq!(client.get_approved(&token_id), None);
+        assert_eq!(
+            client.try_approve(&user1, &market, &token_id, &100),
+            Err(Ok(Error::InvalidLiveUntilLedger))
+        );
+    }
+
+    #[test]
+    fn test_burns_remove_tokens_and_are_counted() {
+        let env = Env::default();
+        let contract_id = env.register(NftContract, ());
+        let client = NftContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let user1 = Address::generate(&env);
+        let market = Address::generate(&env);
+        client.initialize(&admin, &name_of(&env), &symbol_of(&env));
+
+        env.mock_all_auths();
+        let name = String::from_str(&env, "Pass");
+        let first = client.mint(&user1, &name, &name, &name, &None);
+        let second = client.mint(&user1, &name, &name, &name, &None);
+        let third = client.mint(&user1, &name, &name, &name, &None);
+
+        assert_eq!(client.try_burn(&market, &first), Err(Ok(Error::NotTokenOwner)));
+        client.burn(&user1, &first);
+        assert_eq!(client.try_owner_of(&first), Err(Ok(Error::TokenNotFound)));
+        assert_eq!(client.try_token_uri(&first), Err(Ok(Error::TokenNotFound)));
+
+        assert_eq!(
+            client.try_burn_from(&market, &user1, &second),
+            Err(Ok(Error::NotApproved))
+        );
+        client.approve(&user1, &market, &second, &100);
+        client.burn_from(&market, &user1, &second);
+
+        client.admin_burn(&third);
+        assert_eq!(env.auths()[0].0, admin);
+
+        assert_eq!(client.total_minted(), 3);
+        assert_eq!(client.total_burned(), 3);
+        assert_eq!(client.total_supply(), 0);
+        assert_eq!(client.balance(&user1), 0);
+
+        // Ids keep counting up after burns
+        assert_eq!(client.mint(&user1, &name, &name, &name, &None), 4);
+        assert_eq!(client.total_supply(), 1);
+    }
+
+    #[test]
+    fn test_migrate_moves_v1_mint_counter() {
+        let env = Env::default();
+        let contract_id = env.register(NftContract, ());
+        let client = NftContractClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env), &name_of(&env), &symbol_of(&env));
+        env.mock_all_auths();
+
+        // Recreate a version 1 layout with two tokens minted
+        let holder = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            let storage = env.storage().instance();
+            storage.set(&DataKey::Version, &1u32);
+            storage.set(&DataKey::TotalSupply, &2u32);
+            storage.remove(&DataKey::TotalMinted);
+            storage.remove(&DataKey::TotalBurned);
+            for token_id in 1..=2u32 {
+                storage.set(&DataKey::TokenOwner(token_id), &holder);
+            }
+        });
+
+        // Mints between the upgrade and the migration continue the old count
+        let name = String::from_str(&env, "Pass");
+        assert_eq!(client.mint(&Address::generate(&env), &name, &name, &name, &None), 3);
+        assert_eq!(client.owner_of(&1), holder);
+        assert_eq!(client.total_minted(), 3);
+
+        assert_eq!(client.migrate(&u32::MAX), STORAGE_VERSION);
+        assert_eq!(client.total_minted(), 3);
+        assert_eq!(client.total_burned(), 0);
+        assert_eq!(client.total_supply(), 3);
+        assert_eq!(client.balance(&holder), 2);
+    }
+
+    #[test]
+    fn test_owner_indexes_track_mints_transfers_and_burns() {
+        let env = Env::default();
+        let contract_id = env.register(NftContract, ());
+        let client = NftContractClient::new(&env, &contract_id);
+
+        let user1 = Address::generate(&env);
+        let user2 = Address::generate(&env);
+        client.initialize(&Address::generate(&env), &name_of(&env), &symbol_of(&env));
+
+        env.mock_all_auths();
+        let name = String::from_str(&env, "Pass");
+        for _ in 0..4 {
+            client.mint(&user1, &name, &name, &name, &None);
+        }
+        assert_eq!(client.balance(&user1), 4);
+        assert_eq!(client.tokens_of_owner(&user1, &0, &2), Vec::from_array(&env, [1, 2]));
+        assert_eq!(client.tokens_of_owner(&user1, &2, &10), Vec::from_array(&env, [3, 4]));
+        assert_eq!(client.tokens_of_owner(&user1, &4, &10).len(), 0);
+
+        // The owner's last token fills the gap left by a transfer or burn
+        client.transfer(&user1, &user2, &1);
+        client.burn(&user1, &3);
+        assert_eq!(client.tokens_of_owner(&user1, &0, &10), Vec::from_array(&env, [4, 2]));
+        assert_eq!(client.tokens_of_owner(&user2, &0, &10), Vec::from_array(&env, [1]));
+        assert_eq!(client.balance(&user1), 2);
+        assert_eq!(client.balance(&user2), 1);
+
+        // Migrating a version 2 deployment rebuilds the indexes from token owners
+        env.as_contract(&contract_id, || {
+            let storage = env.storage().persistent();
+            for (owner, index) in [(&user1, 0), (&user1, 1), (&user2, 0)] {
+                storage.remove(&DataKey::OwnedToken(owner.clone(), index));
+            }
+            for token_id in [1, 2, 4] {
+                storage.remove(&DataKey::OwnedTokenIndex(token_id));
+            }
+            for owner in [&user1, &user2] {
+                storage.remove(&DataKey::OwnerBalance(owner.clone()));
+            }
+            env.storage().instance().set(&DataKey::Version, &2u32);
+        });
+        // Tokens the migration hasn't reached yet can still move
+        client.transfer(&user1, &user2, &4);
+        assert_eq!(client.balance(&user2), 1);
+
+        // Each call visits at most `max_tokens` tokens and resumes where the last one stopped
+        assert_eq!(client.migrate(&2), 2);
+        assert_eq!(client.tokens_of_owner(&user1, &0, &10), Vec::from_array(&env, [2]));
+        assert_eq!(client.tokens_of_owner(&user2, &0, &10), Vec::from_array(&env, [4, 1]));
+        assert_eq!(client.migrate(&2), 3);
+        assert_eq!(client.balance(&user1), 1);
+        assert_eq!(client.balance(&user2), 2);
+    }
+
+    #[test]
+    fn test_royalty_info_prefers_token_royalty() {
+        let env = Env::default();
+        let contract_id = env.register(NftContract, ());
+        let client = NftContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let user1 = Address::generate(&env);
+        let creator = Address::generate(&env);
+        client.initialize(&admin, &name_of(&env), &symbol_of(&env));
+
+        env.mock_all_auths();
+        let name = String::from_str(&env, "Pass");
+        let plain = client.mint(&user1, &name, &name, &name, &None);
+        assert_eq!(client.try_royalty_info(&plain, &1000), Err(Ok(Error::RoyaltyNotSet)));
+
+        let greedy = Royalty { receiver: creator.clone(), bps: 5001 };
+        assert_eq!(
+            client.try_mint(&user1, &name, &name, &name, &Some(greedy)),
+            Err(Ok(Error::InvalidRoyalty))
+        );
+        let royalty = Royalty { receiver: creator.clone(), bps: 750 };
+        let token_id = client.mint(&user1, &name, &name, &name, &Some(royalty));
+        assert_eq!(client.royalty_info(&token_id, &10_000), (creator.clone(), 750));
+
+        // Tokens without their own royalty fall back to the collection default
+        client.set_default_royalty(&Some(Royalty { receiver: admin.clone(), bps: 250 }));
+        assert_eq!(client.royalty_info(&plain, &10_000), (admin, 250));
+        assert_eq!(client.royalty_info(&token_id, &10_000), (creator, 750));
+        assert_eq!(client.try_royalty_info(&token_id, &-1), Err(Ok(Error::InvalidSalePrice)));
+
+        client.burn(&user1, &token_id);
+        assert_eq!(client.try_royalty_info(&token_id, &1000), Err(Ok(Error::TokenNotFound)));
+    }
+
+    #[test]
+    fn test_collections_number_editions_up_to_max_supply() {
+        let env = Env::default();
+        let contract_id = env.register(NftContract, ());
+        let client = NftContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        let user1 = Address::generate(&env);
+        client.initialize(&admin, &name_of(&env), &symbol_of(&env));
+
+        env.mock_all_auths();
+        let metadata = NFTMetadata {
+            name: String::from_str(&env, "Backstage Pass"),
+            description: String::from_str(&env, "Tour 2025"),
+            image_url: String::from_str(&env, "https://example.com/pass.png"),
+        };
+        assert_eq!(
+            client.try_create_collection(&creator, &metadata, &0, &None),
+            Err(Ok(Error::InvalidMaxSupply))
+        );
+        let royalty = Royalty { receiver: creator.clone(), bps: 500 };
+        let collection_id = client.create_collection(&creator, &metadata, &2, &Some(royalty));
+        assert_eq!(env.auths()[0].0, creator);
+
+        let first = client.mint_edition(&user1, &collection_id);
+        let second = client.mint_edition(&user1, &collection_id);
+        assert_eq!(
+            client.try_mint_edition(&user1, &collection_id),
+            Err(Ok(Error::CollectionSoldOut))
+        );
+        assert_eq!(
+            client.edition_of(&second),
+            Some(Edition { collection_id, number: 2, max_supply: 2 })
+        );
+        assert_eq!(client.token_metadata(&first), metadata);
+        assert_eq!(client.royalty_info(&first, &10_000), (creator.clone(), 500));
+
+        let collection = client.get_collection(&collection_id);
+        assert_eq!(collection.creator, creator);
+        assert_eq!(collection.minted, 2);
+        assert_eq!(client.remaining_supply(&collection_id), 0);
+        assert_eq!(client.try_get_collection(&9), Err(Ok(Error::CollectionNotFound)));
+
+        // Burning an edition does not free up supply or renumber the others
+        client.burn(&user1, &first);
+        assert_eq!(client.edition_of(&second).unwrap().number, 2);
+        assert_eq!(client.remaining_supply(&collection_id), 0);
+        assert_eq!(client.total_supply(), 1);
     }
 }
\ No newline at end of file
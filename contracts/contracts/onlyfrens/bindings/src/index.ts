@@ -33,17 +33,177 @@ if (typeof window !== 'undefined') {
 
 
 
-export type DataKey = {tag: "Owner", values: void} | {tag: "Balance", values: readonly [string]} | {tag: "NftContract", values: void};
-
 export const Errors = {
+  1: {message:"AlreadyInitialized"},
+  2: {message:"NotInitialized"},
+  3: {message:"AmountBelowMinimum"},
+  4: {message:"InsufficientBalance"},
+  5: {message:"BalanceNotFound"},
+  6: {message:"InvalidRoyaltyBps"},
+  7: {message:"AssetNotAllowed"},
+  8: {message:"AssetAlreadyAllowed"},
+  9: {message:"NoSubscriptionPlan"},
+  10: {message:"InvalidPeriods"},
+  11: {message:"NoPendingOwner"},
+  12: {message:"ContractPaused"},
+  13: {message:"AllowanceMissing"},
+  14: {message:"AllowanceExhausted"},
+  15: {message:"InvalidExpiration"},
+  16: {message:"TooManyAllowances"},
+  17: {message:"InvalidWindow"},
+  18: {message:"ReceiptNotFound"},
+  19: {message:"RefundExceedsCharge"},
+  20: {message:"InvalidSplits"},
+  21: {message:"InvalidHoldPeriod"},
+  22: {message:"InvalidBatchSize"},
+  23: {message:"InvalidTreasury"},
+  24: {message:"AmountOverflow"}
+}
+
+
+export interface Payout {
+  amount: i128;
+  recipient: string;
+}
+
+export type DataKey = {tag: "Owner", values: void} | {tag: "PendingOwner", values: void} | {tag: "Paused", values: void} | {tag: "Version", values: void} | {tag: "Balance", values: readonly [string, string]} | {tag: "NftContract", values: void} | {tag: "Assets", values: void} | {tag: "RoyaltyBps", values: void} | {tag: "CreatorRoyaltyBps", values: readonly [string]} | {tag: "ResaleRoyaltyBps", values: void} | {tag: "SubscriptionPlan", values: readonly [string]} | {tag: "Subscription", values: readonly [string, string]} | {tag: "Allowances", values: readonly [string]} | {tag: "IdempotencyWindow", values: void} | {tag: "SpendNonce", values: readonly [string, Buffer]} | {tag: "ReceiptCount", values: void} | {tag: "Receipt", values: readonly [u64]} | {tag: "HistoryLen", values: readonly [string]} | {tag: "HistoryPage", values: readonly [string, u32]} | {tag: "Splits", values: readonly [string]} | {tag: "HoldPeriod", values: void} | {tag: "Pending", values: readonly [string, string]} | {tag: "Treasury", values: void} | {tag: "TreasuryBalance", values: readonly [string]};
+
+
+export interface Receipt {
+  amount: i128;
+  asset: string;
+  creator: string;
+  creator_amount: i128;
+  from: string;
+  id: u64;
+  payouts: Array<Payout>;
+  platform_amount: i128;
+  product_id: Option<string>;
+  refunded: i128;
+  tx_type: string;
+}
+
+
+export interface Allowance {
+  amount: i128;
+  asset: string;
+  creator: Option<string>;
+  expiration_ledger: u32;
+}
+
+
+export interface SpendItem {
+  amount: i128;
+  asset: string;
+  from_user: string;
+  nonce: Buffer;
+  product_id: Option<string>;
+  to_creator: string;
+  tx_type: string;
+}
+
+
+export interface SplitShare {
+  bps: u32;
+  recipient: string;
+}
+
+export type HistoryKind = {tag: "Deposit", values: void} | {tag: "Withdraw", values: void} | {tag: "Spend", values: void} | {tag: "Earning", values: void} | {tag: "Mint", values: void} | {tag: "Refund", values: void} | {tag: "Chargeback", values: void};
+
+export type SpendResult = {tag: "Applied", values: readonly [SpendOutcome]} | {tag: "Failed", values: readonly [u32]};
+
+
+export interface HistoryEntry {
+  amount: i128;
+  asset: Option<string>;
+  counterparty: Option<string>;
+  kind: HistoryKind;
+  reference: Option<u64>;
+  timestamp: u64;
+}
+
+
+export interface SpendOutcome {
+  creator_amount: i128;
+  platform_amount: i128;
+  receipt_id: u64;
+}
+
+
+export interface Subscription {
+  expires_at: u64;
+}
+
+
+export interface PendingCredit {
+  amount: i128;
+  unlock_ledger: u32;
+}
 
+
+export interface TreasuryShare {
+  recipient: string;
+  weight: u32;
+}
+
+
+export interface BalanceBreakdown {
+  available: i128;
+  next_unlock_ledger: Option<u32>;
+  pending: i128;
+}
+
+
+export interface SubscriptionPlan {
+  asset: string;
+  price: i128;
 }
 
 export interface Client {
   /**
-   * Construct and simulate a initialize transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a tip transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  tip: ({from, to_creator, asset, amount, memo}: {from: string, to_creator: string, asset: string, amount: i128, memo: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u64>>>
+
+  /**
+   * Construct and simulate a pause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  pause: ({allowed}: {allowed: Array<string>}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a refund transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  initialize: ({owner, nft_contract_id}: {owner: string, nft_contract_id: string}, options?: {
+  refund: ({receipt_id, amount}: {receipt_id: u64, amount: i128}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -58,12 +218,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>
+  }) => Promise<AssembledTransaction<Result<Receipt>>>
 
   /**
    * Construct and simulate a deposit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  deposit: ({user, amount}: {user: string, amount: i128}, options?: {
+  deposit: ({user, asset, amount}: {user: string, asset: string, amount: i128}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -78,12 +238,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>
+  }) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a get_balance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a migrate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_balance: ({user}: {user: string}, options?: {
+  migrate: ({legacy_users}: {legacy_users: Array<string>}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -98,12 +258,52 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<i128>>
+  }) => Promise<AssembledTransaction<Result<u32>>>
 
   /**
-   * Construct and simulate a admin_spend transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a unpause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  unpause: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a version transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  admin_spend: ({from_user, to_creator, amount, tx_type, product_id}: {from_user: string, to_creator: string, amount: i128, tx_type: SorobanString, product_id: Option<SorobanString>}, options?: {
+  version: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -118,12 +318,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>
+  }) => Promise<AssembledTransaction<Result<u32>>>
 
   /**
    * Construct and simulate a mint_nft transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  mint_nft: ({to, name, description, image_url}: {to: string, name: SorobanString, description: SorobanString, image_url: SorobanString}, options?: {
+  mint_nft: ({to, name, description, image_url}: {to: string, name: string, description: string, image_url: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -138,12 +338,932 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<u32>>
+  }) => Promise<AssembledTransaction<Result<u32>>>
 
   /**
    * Construct and simulate a withdraw transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  withdraw: ({user, amount}: {user: string, amount: i128}, options?: {
+  withdraw: ({user, asset, amount}: {user: string, asset: string, amount: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a add_asset transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  add_asset: ({asset}: {asset: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a claw_back transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  claw_back: ({creator, asset, amount, to}: {creator: string, asset: string, amount: i128, to: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_owner transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_owner: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<string>>>
+
+  /**
+   * Construct and simulate a is_paused transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  is_paused: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<boolean>>>
+
+  /**
+   * Construct and simulate a subscribe transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  subscribe: ({user, creator, periods}: {user: string, creator: string, periods: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u64>>>
+
+  /**
+   * Construct and simulate a get_assets transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_assets: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<Array<string>>>>
+
+  /**
+   * Construct and simulate a get_splits transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_splits: ({creator}: {creator: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<Array<SplitShare>>>>
+
+  /**
+   * Construct and simulate a initialize transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  initialize: ({owner, nft_contract_id, asset}: {owner: string, nft_contract_id: string, asset: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_splits transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_splits: ({creator, shares}: {creator: string, shares: Array<SplitShare>}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a admin_spend transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  admin_spend: ({from_user, to_creator, asset, amount, tx_type, product_id, nonce}: {from_user: string, to_creator: string, asset: string, amount: i128, tx_type: string, product_id: Option<string>, nonce: Buffer}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<SpendOutcome>>>
+
+  /**
+   * Construct and simulate a get_balance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_balance: ({user, asset}: {user: string, asset: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a get_history transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_history: ({address, page}: {address: string, page: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<Array<HistoryEntry>>>>
+
+  /**
+   * Construct and simulate a get_receipt transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_receipt: ({receipt_id}: {receipt_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<Receipt>>>
+
+  /**
+   * Construct and simulate a clear_splits transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  clear_splits: ({creator}: {creator: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_treasury transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_treasury: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<Array<TreasuryShare>>>>
+
+  /**
+   * Construct and simulate a purchase_nft transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  purchase_nft: ({buyer, creator, asset, price, name, description, image_url}: {buyer: string, creator: string, asset: string, price: i128, name: string, description: string, image_url: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a remove_asset transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  remove_asset: ({asset}: {asset: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_treasury transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_treasury: ({recipients}: {recipients: Array<TreasuryShare>}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a is_subscribed transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  is_subscribed: ({user, creator}: {user: string, creator: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<boolean>>>
+
+  /**
+   * Construct and simulate a propose_owner transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  propose_owner: ({new_owner}: {new_owner: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_allowances transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_allowances: ({user}: {user: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<Array<Allowance>>>>
+
+  /**
+   * Construct and simulate a sweep_treasury transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  sweep_treasury: ({asset}: {asset: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a get_history_len transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_history_len: ({address}: {address: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a get_hold_period transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_hold_period: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a get_royalty_bps transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_royalty_bps: ({creator}: {creator: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a revoke_spending transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  revoke_spending: ({user, creator, asset}: {user: string, creator: Option<string>, asset: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_hold_period transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_hold_period: ({ledgers}: {ledgers: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_royalty_bps transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_royalty_bps: ({bps}: {bps: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a accept_ownership transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  accept_ownership: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a approve_spending transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  approve_spending: ({user, creator, asset, amount, expiration_ledger}: {user: string, creator: Option<string>, asset: string, amount: i128, expiration_ledger: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_subscription transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_subscription: ({user, creator}: {user: string, creator: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<Option<Subscription>>>>
+
+  /**
+   * Construct and simulate a is_asset_allowed transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  is_asset_allowed: ({asset}: {asset: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<boolean>>>
+
+  /**
+   * Construct and simulate a admin_spend_batch transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  admin_spend_batch: ({items, atomic}: {items: Array<SpendItem>, atomic: boolean}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<Array<SpendResult>>>>
+
+  /**
+   * Construct and simulate a get_pending_owner transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_pending_owner: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<Option<string>>>>
+
+  /**
+   * Construct and simulate a extend_balance_ttl transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  extend_balance_ttl: ({user, asset}: {user: string, asset: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_pause_allowlist transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_pause_allowlist: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<Array<string>>>>
+
+  /**
+   * Construct and simulate a get_treasury_balance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_treasury_balance: ({asset}: {asset: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a get_balance_breakdown transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_balance_breakdown: ({user, asset}: {user: string, asset: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<BalanceBreakdown>>>
+
+  /**
+   * Construct and simulate a get_subscription_plan transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_subscription_plan: ({creator}: {creator: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<SubscriptionPlan>>>
+
+  /**
+   * Construct and simulate a set_subscription_plan transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_subscription_plan: ({creator, asset, price}: {creator: string, asset: string, price: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_idempotency_window transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_idempotency_window: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a get_resale_royalty_bps transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_resale_royalty_bps: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a set_idempotency_window transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_idempotency_window: ({ledgers}: {ledgers: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_resale_royalty_bps transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_resale_royalty_bps: ({bps}: {bps: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_creator_royalty_bps transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_creator_royalty_bps: ({creator, bps}: {creator: string, bps: Option<u32>}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a cancel_ownership_transfer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  cancel_ownership_transfer: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -158,12 +1278,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>
+  }) => Promise<AssembledTransaction<Result<void>>>
 
 }
 export class Client extends ContractClient {
   static async deploy<T = Client>(
-    /** Options for initalizing a Client as well as for calling a method, with extras specific to deploying. */
+    /** Options for initializing a Client as well as for calling a method, with extras specific to deploying. */
     options: MethodOptions &
       Omit<ContractClientOptions, "contractId"> & {
         /** The hash of the Wasm blob, which must already be installed on-chain. */
@@ -178,22 +1298,137 @@ export class Client extends ContractClient {
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAAwAAAAAAAAAAAAAABU93bmVyAAAAAAAAAQAAAAAAAAAHQmFsYW5jZQAAAAABAAAAEwAAAAAAAAAAAAAAC05mdENvbnRyYWN0AA==",
-        "AAAAAAAAAAAAAAAKaW5pdGlhbGl6ZQAAAAAAAgAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAA9uZnRfY29udHJhY3RfaWQAAAAAEwAAAAA=",
-        "AAAAAAAAAAAAAAAHZGVwb3NpdAAAAAACAAAAAAAAAAR1c2VyAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAA==",
-        "AAAAAAAAAAAAAAALZ2V0X2JhbGFuY2UAAAAAAQAAAAAAAAAEdXNlcgAAABMAAAABAAAACw==",
-        "AAAAAAAAAAAAAAALYWRtaW5fc3BlbmQAAAAABQAAAAAAAAAJZnJvbV91c2VyAAAAAAAAEwAAAAAAAAAKdG9fY3JlYXRvcgAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAd0eF90eXBlAAAAB9AAAAANU29yb2JhblN0cmluZwAAAAAAAAAAAAAKcHJvZHVjdF9pZAAAAAAD6AAAB9AAAAANU29yb2JhblN0cmluZwAAAAAAAAA=",
-        "AAAAAAAAAAAAAAAIbWludF9uZnQAAAAEAAAAAAAAAAJ0bwAAAAAAEwAAAAAAAAAEbmFtZQAAB9AAAAANU29yb2JhblN0cmluZwAAAAAAAAAAAAALZGVzY3JpcHRpb24AAAAH0AAAAA1Tb3JvYmFuU3RyaW5nAAAAAAAAAAAAAAlpbWFnZV91cmwAAAAAAAfQAAAADVNvcm9iYW5TdHJpbmcAAAAAAAABAAAABA==",
-        "AAAAAAAAAAAAAAAId2l0aGRyYXcAAAACAAAAAAAAAAR1c2VyAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAA==" ]),
+      new ContractSpec([ "AAAAAAAAAAAAAAADdGlwAAAAAAUAAAAAAAAABGZyb20AAAATAAAAAAAAAAp0b19jcmVhdG9yAAAAAAATAAAAAAAAAAVhc3NldAAAAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAEbWVtbwAAABAAAAABAAAD6QAAAAYAAAAD",
+        "AAAAAAAAAAAAAAAFcGF1c2UAAAAAAAABAAAAAAAAAAdhbGxvd2VkAAAAA+oAAAARAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAAAAAAAGcmVmdW5kAAAAAAACAAAAAAAAAApyZWNlaXB0X2lkAAAAAAAGAAAAAAAAAAZhbW91bnQAAAAAAAsAAAABAAAD6QAAB9AAAAAHUmVjZWlwdAAAAAAD",
+        "AAAAAAAAAAAAAAAHZGVwb3NpdAAAAAADAAAAAAAAAAR1c2VyAAAAEwAAAAAAAAAFYXNzZXQAAAAAAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAAAAAAAHbWlncmF0ZQAAAAABAAAAAAAAAAxsZWdhY3lfdXNlcnMAAAPqAAAAEwAAAAEAAAPpAAAABAAAAAM=",
+        "AAAAAAAAAAAAAAAHdW5wYXVzZQAAAAAAAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAAAAAAAHdXBncmFkZQAAAAABAAAAAAAAAA1uZXdfd2FzbV9oYXNoAAAAAAAD7gAAACAAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAAAAAAAHdmVyc2lvbgAAAAAAAAAAAQAAA+kAAAAEAAAAAw==",
+        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAAGAAAAAAAAAASQWxyZWFkeUluaXRpYWxpemVkAAAAAAABAAAAAAAAAA5Ob3RJbml0aWFsaXplZAAAAAAAAgAAAAAAAAASQW1vdW50QmVsb3dNaW5pbXVtAAAAAAADAAAAAAAAABNJbnN1ZmZpY2llbnRCYWxhbmNlAAAAAAQAAAAAAAAAD0JhbGFuY2VOb3RGb3VuZAAAAAAFAAAAAAAAABFJbnZhbGlkUm95YWx0eUJwcwAAAAAAAAYAAAAAAAAAD0Fzc2V0Tm90QWxsb3dlZAAAAAAHAAAAAAAAABNBc3NldEFscmVhZHlBbGxvd2VkAAAAAAgAAAAAAAAAEk5vU3Vic2NyaXB0aW9uUGxhbgAAAAAACQAAAAAAAAAOSW52YWxpZFBlcmlvZHMAAAAAAAoAAAAAAAAADk5vUGVuZGluZ093bmVyAAAAAAALAAAAAAAAAA5Db250cmFjdFBhdXNlZAAAAAAADAAAAAAAAAAQQWxsb3dhbmNlTWlzc2luZwAAAA0AAAAAAAAAEkFsbG93YW5jZUV4aGF1c3RlZAAAAAAADgAAAAAAAAARSW52YWxpZEV4cGlyYXRpb24AAAAAAAAPAAAAAAAAABFUb29NYW55QWxsb3dhbmNlcwAAAAAAABAAAAAAAAAADUludmFsaWRXaW5kb3cAAAAAAAARAAAAAAAAAA9SZWNlaXB0Tm90Rm91bmQAAAAAEgAAAAAAAAATUmVmdW5kRXhjZWVkc0NoYXJnZQAAAAATAAAAAAAAAA1JbnZhbGlkU3BsaXRzAAAAAAAAFAAAAAAAAAARSW52YWxpZEhvbGRQZXJpb2QAAAAAAAAVAAAAAAAAABBJbnZhbGlkQmF0Y2hTaXplAAAAFgAAAAAAAAAPSW52YWxpZFRyZWFzdXJ5AAAAABcAAAAAAAAADkFtb3VudE92ZXJmbG93AAAAAAAY",
+        "AAAAAAAAAAAAAAAIbWludF9uZnQAAAAEAAAAAAAAAAJ0bwAAAAAAEwAAAAAAAAAEbmFtZQAAABAAAAAAAAAAC2Rlc2NyaXB0aW9uAAAAABAAAAAAAAAACWltYWdlX3VybAAAAAAAABAAAAABAAAD6QAAAAQAAAAD",
+        "AAAAAAAAAAAAAAAId2l0aGRyYXcAAAADAAAAAAAAAAR1c2VyAAAAEwAAAAAAAAAFYXNzZXQAAAAAAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAQAAAAAAAAAAAAAABlBheW91dAAAAAAAAgAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAlyZWNpcGllbnQAAAAAAAAT",
+        "AAAAAAAAAAAAAAAJYWRkX2Fzc2V0AAAAAAAAAQAAAAAAAAAFYXNzZXQAAAAAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAAAAAAAJY2xhd19iYWNrAAAAAAAABAAAAAAAAAAHY3JlYXRvcgAAAAATAAAAAAAAAAVhc3NldAAAAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAACdG8AAAAAABMAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAAAAAAAJZ2V0X293bmVyAAAAAAAAAAAAAAEAAAPpAAAAEwAAAAM=",
+        "AAAAAAAAAAAAAAAJaXNfcGF1c2VkAAAAAAAAAAAAAAEAAAPpAAAAAQAAAAM=",
+        "AAAAAAAAAAAAAAAJc3Vic2NyaWJlAAAAAAAAAwAAAAAAAAAEdXNlcgAAABMAAAAAAAAAB2NyZWF0b3IAAAAAEwAAAAAAAAAHcGVyaW9kcwAAAAAEAAAAAQAAA+kAAAAGAAAAAw==",
+        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAGAAAAAAAAAAAAAAABU93bmVyAAAAAAAAAAAAAAAAAAAMUGVuZGluZ093bmVyAAAAAAAAAAAAAAAGUGF1c2VkAAAAAAAAAAAAAAAAAAdWZXJzaW9uAAAAAAEAAAAAAAAAB0JhbGFuY2UAAAAAAgAAABMAAAATAAAAAAAAAAAAAAALTmZ0Q29udHJhY3QAAAAAAAAAAAAAAAAGQXNzZXRzAAAAAAAAAAAAAAAAAApSb3lhbHR5QnBzAAAAAAABAAAAAAAAABFDcmVhdG9yUm95YWx0eUJwcwAAAAAAAAEAAAATAAAAAAAAAAAAAAAQUmVzYWxlUm95YWx0eUJwcwAAAAEAAAAAAAAAEFN1YnNjcmlwdGlvblBsYW4AAAABAAAAEwAAAAEAAAAAAAAADFN1YnNjcmlwdGlvbgAAAAIAAAATAAAAEwAAAAEAAAAAAAAACkFsbG93YW5jZXMAAAAAAAEAAAATAAAAAAAAAAAAAAARSWRlbXBvdGVuY3lXaW5kb3cAAAAAAAABAAAAAAAAAApTcGVuZE5vbmNlAAAAAAACAAAAEwAAA+4AAAAgAAAAAAAAAAAAAAAMUmVjZWlwdENvdW50AAAAAQAAAAAAAAAHUmVjZWlwdAAAAAABAAAABgAAAAEAAAAAAAAACkhpc3RvcnlMZW4AAAAAAAEAAAATAAAAAQAAAAAAAAALSGlzdG9yeVBhZ2UAAAAAAgAAABMAAAAEAAAAAQAAAAAAAAAGU3BsaXRzAAAAAAABAAAAEwAAAAAAAAAAAAAACkhvbGRQZXJpb2QAAAAAAAEAAAAAAAAAB1BlbmRpbmcAAAAAAgAAABMAAAATAAAAAAAAAAAAAAAIVHJlYXN1cnkAAAABAAAAAAAAAA9UcmVhc3VyeUJhbGFuY2UAAAAAAQAAABM=",
+        "AAAAAQAAAAAAAAAAAAAAB1JlY2VpcHQAAAAACwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAVhc3NldAAAAAAAABMAAAAAAAAAB2NyZWF0b3IAAAAAEwAAAAAAAAAOY3JlYXRvcl9hbW91bnQAAAAAAAsAAAAAAAAABGZyb20AAAATAAAAAAAAAAJpZAAAAAAABgAAAAAAAAAHcGF5b3V0cwAAAAPqAAAH0AAAAAZQYXlvdXQAAAAAAAAAAAAPcGxhdGZvcm1fYW1vdW50AAAAAAsAAAAAAAAACnByb2R1Y3RfaWQAAAAAA+gAAAAQAAAAAAAAAAhyZWZ1bmRlZAAAAAsAAAAAAAAAB3R4X3R5cGUAAAAAEA==",
+        "AAAAAAAAAAAAAAAKZ2V0X2Fzc2V0cwAAAAAAAAAAAAEAAAPpAAAD6gAAABMAAAAD",
+        "AAAAAAAAAAAAAAAKZ2V0X3NwbGl0cwAAAAAAAQAAAAAAAAAHY3JlYXRvcgAAAAATAAAAAQAAA+kAAAPqAAAH0AAAAApTcGxpdFNoYXJlAAAAAAAD",
+        "AAAAAAAAAAAAAAAKaW5pdGlhbGl6ZQAAAAAAAwAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAA9uZnRfY29udHJhY3RfaWQAAAAAEwAAAAAAAAAFYXNzZXQAAAAAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAAAAAAAKc2V0X3NwbGl0cwAAAAAAAgAAAAAAAAAHY3JlYXRvcgAAAAATAAAAAAAAAAZzaGFyZXMAAAAAA+oAAAfQAAAAClNwbGl0U2hhcmUAAAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAAAAAAALYWRtaW5fc3BlbmQAAAAABwAAAAAAAAAJZnJvbV91c2VyAAAAAAAAEwAAAAAAAAAKdG9fY3JlYXRvcgAAAAAAEwAAAAAAAAAFYXNzZXQAAAAAAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAB3R4X3R5cGUAAAAAEAAAAAAAAAAKcHJvZHVjdF9pZAAAAAAD6AAAABAAAAAAAAAABW5vbmNlAAAAAAAD7gAAACAAAAABAAAD6QAAB9AAAAAMU3BlbmRPdXRjb21lAAAAAw==",
+        "AAAAAAAAAAAAAAALZ2V0X2JhbGFuY2UAAAAAAgAAAAAAAAAEdXNlcgAAABMAAAAAAAAABWFzc2V0AAAAAAAAEwAAAAEAAAPpAAAACwAAAAM=",
+        "AAAAAAAAAAAAAAALZ2V0X2hpc3RvcnkAAAAAAgAAAAAAAAAHYWRkcmVzcwAAAAATAAAAAAAAAARwYWdlAAAABAAAAAEAAAPpAAAD6gAAB9AAAAAMSGlzdG9yeUVudHJ5AAAAAw==",
+        "AAAAAAAAAAAAAAALZ2V0X3JlY2VpcHQAAAAAAQAAAAAAAAAKcmVjZWlwdF9pZAAAAAAABgAAAAEAAAPpAAAH0AAAAAdSZWNlaXB0AAAAAAM=",
+        "AAAAAQAAAAAAAAAAAAAACUFsbG93YW5jZQAAAAAAAAQAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAFYXNzZXQAAAAAAAATAAAAAAAAAAdjcmVhdG9yAAAAA+gAAAATAAAAAAAAABFleHBpcmF0aW9uX2xlZGdlcgAAAAAAAAQ=",
+        "AAAAAQAAAAAAAAAAAAAACVNwZW5kSXRlbQAAAAAAAAcAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAFYXNzZXQAAAAAAAATAAAAAAAAAAlmcm9tX3VzZXIAAAAAAAATAAAAAAAAAAVub25jZQAAAAAAA+4AAAAgAAAAAAAAAApwcm9kdWN0X2lkAAAAAAPoAAAAEAAAAAAAAAAKdG9fY3JlYXRvcgAAAAAAEwAAAAAAAAAHdHhfdHlwZQAAAAAQ",
+        "AAAAAAAAAAAAAAAMY2xlYXJfc3BsaXRzAAAAAQAAAAAAAAAHY3JlYXRvcgAAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAAAAAAAMZ2V0X3RyZWFzdXJ5AAAAAAAAAAEAAAPpAAAD6gAAB9AAAAANVHJlYXN1cnlTaGFyZQAAAAAAAAM=",
+        "AAAAAAAAAAAAAAAMcHVyY2hhc2VfbmZ0AAAABwAAAAAAAAAFYnV5ZXIAAAAAAAATAAAAAAAAAAdjcmVhdG9yAAAAABMAAAAAAAAABWFzc2V0AAAAAAAAEwAAAAAAAAAFcHJpY2UAAAAAAAALAAAAAAAAAARuYW1lAAAAEAAAAAAAAAALZGVzY3JpcHRpb24AAAAAEAAAAAAAAAAJaW1hZ2VfdXJsAAAAAAAAEAAAAAEAAAPpAAAABAAAAAM=",
+        "AAAAAAAAAAAAAAAMcmVtb3ZlX2Fzc2V0AAAAAQAAAAAAAAAFYXNzZXQAAAAAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAAAAAAAMc2V0X3RyZWFzdXJ5AAAAAQAAAAAAAAAKcmVjaXBpZW50cwAAAAAD6gAAB9AAAAANVHJlYXN1cnlTaGFyZQAAAAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAQAAAAAAAAAAAAAAClNwbGl0U2hhcmUAAAAAAAIAAAAAAAAAA2JwcwAAAAAEAAAAAAAAAAlyZWNpcGllbnQAAAAAAAAT",
+        "AAAAAAAAAAAAAAANaXNfc3Vic2NyaWJlZAAAAAAAAAIAAAAAAAAABHVzZXIAAAATAAAAAAAAAAdjcmVhdG9yAAAAABMAAAABAAAD6QAAAAEAAAAD",
+        "AAAAAAAAAAAAAAANcHJvcG9zZV9vd25lcgAAAAAAAAEAAAAAAAAACW5ld19vd25lcgAAAAAAABMAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAgAAAAAAAAAAAAAAC0hpc3RvcnlLaW5kAAAAAAcAAAAAAAAAAAAAAAdEZXBvc2l0AAAAAAAAAAAAAAAACFdpdGhkcmF3AAAAAAAAAAAAAAAFU3BlbmQAAAAAAAAAAAAAAAAAAAdFYXJuaW5nAAAAAAAAAAAAAAAABE1pbnQAAAAAAAAAAAAAAAZSZWZ1bmQAAAAAAAAAAAAAAAAACkNoYXJnZWJhY2sAAA==",
+        "AAAAAgAAAAAAAAAAAAAAC1NwZW5kUmVzdWx0AAAAAAIAAAABAAAAAAAAAAdBcHBsaWVkAAAAAAEAAAfQAAAADFNwZW5kT3V0Y29tZQAAAAEAAAAAAAAABkZhaWxlZAAAAAAAAQAAAAQ=",
+        "AAAAAAAAAAAAAAAOZ2V0X2FsbG93YW5jZXMAAAAAAAEAAAAAAAAABHVzZXIAAAATAAAAAQAAA+kAAAPqAAAH0AAAAAlBbGxvd2FuY2UAAAAAAAAD",
+        "AAAAAAAAAAAAAAAOc3dlZXBfdHJlYXN1cnkAAAAAAAEAAAAAAAAABWFzc2V0AAAAAAAAEwAAAAEAAAPpAAAACwAAAAM=",
+        "AAAAAQAAAAAAAAAAAAAADEhpc3RvcnlFbnRyeQAAAAYAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAFYXNzZXQAAAAAAAPoAAAAEwAAAAAAAAAMY291bnRlcnBhcnR5AAAD6AAAABMAAAAAAAAABGtpbmQAAAfQAAAAC0hpc3RvcnlLaW5kAAAAAAAAAAAJcmVmZXJlbmNlAAAAAAAD6AAAAAYAAAAAAAAACXRpbWVzdGFtcAAAAAAAAAY=",
+        "AAAAAQAAAAAAAAAAAAAADFNwZW5kT3V0Y29tZQAAAAMAAAAAAAAADmNyZWF0b3JfYW1vdW50AAAAAAALAAAAAAAAAA9wbGF0Zm9ybV9hbW91bnQAAAAACwAAAAAAAAAKcmVjZWlwdF9pZAAAAAAABg==",
+        "AAAAAQAAAAAAAAAAAAAADFN1YnNjcmlwdGlvbgAAAAEAAAAAAAAACmV4cGlyZXNfYXQAAAAAAAY=",
+        "AAAAAAAAAAAAAAAPZ2V0X2hpc3RvcnlfbGVuAAAAAAEAAAAAAAAAB2FkZHJlc3MAAAAAEwAAAAEAAAPpAAAABAAAAAM=",
+        "AAAAAAAAAAAAAAAPZ2V0X2hvbGRfcGVyaW9kAAAAAAAAAAABAAAD6QAAAAQAAAAD",
+        "AAAAAAAAAAAAAAAPZ2V0X3JveWFsdHlfYnBzAAAAAAEAAAAAAAAAB2NyZWF0b3IAAAAAEwAAAAEAAAPpAAAABAAAAAM=",
+        "AAAAAAAAAAAAAAAPcmV2b2tlX3NwZW5kaW5nAAAAAAMAAAAAAAAABHVzZXIAAAATAAAAAAAAAAdjcmVhdG9yAAAAA+gAAAATAAAAAAAAAAVhc3NldAAAAAAAABMAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAAAAAAAPc2V0X2hvbGRfcGVyaW9kAAAAAAEAAAAAAAAAB2xlZGdlcnMAAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAAAAAAAPc2V0X3JveWFsdHlfYnBzAAAAAAEAAAAAAAAAA2JwcwAAAAAEAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAQAAAAAAAAAAAAAADVBlbmRpbmdDcmVkaXQAAAAAAAACAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAADXVubG9ja19sZWRnZXIAAAAAAAAE",
+        "AAAAAQAAAAAAAAAAAAAADVRyZWFzdXJ5U2hhcmUAAAAAAAACAAAAAAAAAAlyZWNpcGllbnQAAAAAAAATAAAAAAAAAAZ3ZWlnaHQAAAAAAAQ=",
+        "AAAAAAAAAAAAAAAQYWNjZXB0X293bmVyc2hpcAAAAAAAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAAAAAAAQYXBwcm92ZV9zcGVuZGluZwAAAAUAAAAAAAAABHVzZXIAAAATAAAAAAAAAAdjcmVhdG9yAAAAA+gAAAATAAAAAAAAAAVhc3NldAAAAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAARZXhwaXJhdGlvbl9sZWRnZXIAAAAAAAAEAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAAAAAAAQZ2V0X3N1YnNjcmlwdGlvbgAAAAIAAAAAAAAABHVzZXIAAAATAAAAAAAAAAdjcmVhdG9yAAAAABMAAAABAAAD6QAAA+gAAAfQAAAADFN1YnNjcmlwdGlvbgAAAAM=",
+        "AAAAAAAAAAAAAAAQaXNfYXNzZXRfYWxsb3dlZAAAAAEAAAAAAAAABWFzc2V0AAAAAAAAEwAAAAEAAAPpAAAAAQAAAAM=",
+        "AAAAAAAAAAAAAAARYWRtaW5fc3BlbmRfYmF0Y2gAAAAAAAACAAAAAAAAAAVpdGVtcwAAAAAAA+oAAAfQAAAACVNwZW5kSXRlbQAAAAAAAAAAAAAGYXRvbWljAAAAAAABAAAAAQAAA+kAAAPqAAAH0AAAAAtTcGVuZFJlc3VsdAAAAAAD",
+        "AAAAAAAAAAAAAAARZ2V0X3BlbmRpbmdfb3duZXIAAAAAAAAAAAAAAQAAA+kAAAPoAAAAEwAAAAM=",
+        "AAAAAAAAAAAAAAASZXh0ZW5kX2JhbGFuY2VfdHRsAAAAAAACAAAAAAAAAAR1c2VyAAAAEwAAAAAAAAAFYXNzZXQAAAAAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAQAAAAAAAAAAAAAAEEJhbGFuY2VCcmVha2Rvd24AAAADAAAAAAAAAAlhdmFpbGFibGUAAAAAAAALAAAAAAAAABJuZXh0X3VubG9ja19sZWRnZXIAAAAAA+gAAAAEAAAAAAAAAAdwZW5kaW5nAAAAAAs=",
+        "AAAAAQAAAAAAAAAAAAAAEFN1YnNjcmlwdGlvblBsYW4AAAACAAAAAAAAAAVhc3NldAAAAAAAABMAAAAAAAAABXByaWNlAAAAAAAACw==",
+        "AAAAAAAAAAAAAAATZ2V0X3BhdXNlX2FsbG93bGlzdAAAAAAAAAAAAQAAA+kAAAPqAAAAEQAAAAM=",
+        "AAAAAAAAAAAAAAAUZ2V0X3RyZWFzdXJ5X2JhbGFuY2UAAAABAAAAAAAAAAVhc3NldAAAAAAAABMAAAABAAAD6QAAAAsAAAAD",
+        "AAAAAAAAAAAAAAAVZ2V0X2JhbGFuY2VfYnJlYWtkb3duAAAAAAAAAgAAAAAAAAAEdXNlcgAAABMAAAAAAAAABWFzc2V0AAAAAAAAEwAAAAEAAAPpAAAH0AAAABBCYWxhbmNlQnJlYWtkb3duAAAAAw==",
+        "AAAAAAAAAAAAAAAVZ2V0X3N1YnNjcmlwdGlvbl9wbGFuAAAAAAAAAQAAAAAAAAAHY3JlYXRvcgAAAAATAAAAAQAAA+kAAAfQAAAAEFN1YnNjcmlwdGlvblBsYW4AAAAD",
+        "AAAAAAAAAAAAAAAVc2V0X3N1YnNjcmlwdGlvbl9wbGFuAAAAAAAAAwAAAAAAAAAHY3JlYXRvcgAAAAATAAAAAAAAAAVhc3NldAAAAAAAABMAAAAAAAAABXByaWNlAAAAAAAACwAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAAAAAAAWZ2V0X2lkZW1wb3RlbmN5X3dpbmRvdwAAAAAAAAAAAAEAAAPpAAAABAAAAAM=",
+        "AAAAAAAAAAAAAAAWZ2V0X3Jlc2FsZV9yb3lhbHR5X2JwcwAAAAAAAAAAAAEAAAPpAAAABAAAAAM=",
+        "AAAAAAAAAAAAAAAWc2V0X2lkZW1wb3RlbmN5X3dpbmRvdwAAAAAAAQAAAAAAAAAHbGVkZ2VycwAAAAAEAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAAAAAAAWc2V0X3Jlc2FsZV9yb3lhbHR5X2JwcwAAAAAAAQAAAAAAAAADYnBzAAAAAAQAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAAAAAAAXc2V0X2NyZWF0b3Jfcm95YWx0eV9icHMAAAAAAgAAAAAAAAAHY3JlYXRvcgAAAAATAAAAAAAAAANicHMAAAAD6AAAAAQAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAAAAAAAZY2FuY2VsX293bmVyc2hpcF90cmFuc2ZlcgAAAAAAAAAAAAABAAAD6QAAA+0AAAAAAAAAAw==" ]),
       options
     )
   }
   public readonly fromJSON = {
-    initialize: this.txFromJSON<null>,
-        deposit: this.txFromJSON<null>,
-        get_balance: this.txFromJSON<i128>,
-        admin_spend: this.txFromJSON<null>,
-        mint_nft: this.txFromJSON<u32>,
-        withdraw: this.txFromJSON<null>
+    tip: this.txFromJSON<Result<u64>>,
+        pause: this.txFromJSON<Result<void>>,
+        refund: this.txFromJSON<Result<Receipt>>,
+        deposit: this.txFromJSON<Result<void>>,
+        migrate: this.txFromJSON<Result<u32>>,
+        unpause: this.txFromJSON<Result<void>>,
+        upgrade: this.txFromJSON<Result<void>>,
+        version: this.txFromJSON<Result<u32>>,
+        mint_nft: this.txFromJSON<Result<u32>>,
+        withdraw: this.txFromJSON<Result<void>>,
+        add_asset: this.txFromJSON<Result<void>>,
+        claw_back: this.txFromJSON<Result<void>>,
+        get_owner: this.txFromJSON<Result<string>>,
+        is_paused: this.txFromJSON<Result<boolean>>,
+        subscribe: this.txFromJSON<Result<u64>>,
+        get_assets: this.txFromJSON<Result<Array<string>>>,
+        get_splits: this.txFromJSON<Result<Array<SplitShare>>>,
+        initialize: this.txFromJSON<Result<void>>,
+        set_splits: this.txFromJSON<Result<void>>,
+        admin_spend: this.txFromJSON<Result<SpendOutcome>>,
+        get_balance: this.txFromJSON<Result<i128>>,
+        get_history: this.txFromJSON<Result<Array<HistoryEntry>>>,
+        get_receipt: this.txFromJSON<Result<Receipt>>,
+        clear_splits: this.txFromJSON<Result<void>>,
+        get_treasury: this.txFromJSON<Result<Array<TreasuryShare>>>,
+        purchase_nft: this.txFromJSON<Result<u32>>,
+        remove_asset: this.txFromJSON<Result<void>>,
+        set_treasury: this.txFromJSON<Result<void>>,
+        is_subscribed: this.txFromJSON<Result<boolean>>,
+        propose_owner: this.txFromJSON<Result<void>>,
+        get_allowances: this.txFromJSON<Result<Array<Allowance>>>,
+        sweep_treasury: this.txFromJSON<Result<i128>>,
+        get_history_len: this.txFromJSON<Result<u32>>,
+        get_hold_period: this.txFromJSON<Result<u32>>,
+        get_royalty_bps: this.txFromJSON<Result<u32>>,
+        revoke_spending: this.txFromJSON<Result<void>>,
+        set_hold_period: this.txFromJSON<Result<void>>,
+        set_royalty_bps: this.txFromJSON<Result<void>>,
+        accept_ownership: this.txFromJSON<Result<void>>,
+        approve_spending: this.txFromJSON<Result<void>>,
+        get_subscription: this.txFromJSON<Result<Option<Subscription>>>,
+        is_asset_allowed: this.txFromJSON<Result<boolean>>,
+        admin_spend_batch: this.txFromJSON<Result<Array<SpendResult>>>,
+        get_pending_owner: this.txFromJSON<Result<Option<string>>>,
+        extend_balance_ttl: this.txFromJSON<Result<void>>,
+        get_pause_allowlist: this.txFromJSON<Result<Array<string>>>,
+        get_treasury_balance: this.txFromJSON<Result<i128>>,
+        get_balance_breakdown: this.txFromJSON<Result<BalanceBreakdown>>,
+        get_subscription_plan: this.txFromJSON<Result<SubscriptionPlan>>,
+        set_subscription_plan: this.txFromJSON<Result<void>>,
+        get_idempotency_window: this.txFromJSON<Result<u32>>,
+        get_resale_royalty_bps: this.txFromJSON<Result<u32>>,
+        set_idempotency_window: this.txFromJSON<Result<void>>,
+        set_resale_royalty_bps: this.txFromJSON<Result<void>>,
+        set_creator_royalty_bps: this.txFromJSON<Result<void>>,
+        cancel_ownership_transfer: this.txFromJSON<Result<void>>
   }
 }
\ No newline at end of file
@@ -1,28 +1,250 @@
 #![no_std]
-use soroban_sdk::String as SorobanString;
 use core::clone::Clone;
 use core::convert::{Into, From};
 use core::iter::{Iterator, ExactSizeIterator};
 use core::option::Option;
 use core::result::Result;
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short, Address, Env
+    contract, contracterror, contractimpl, contracttype, symbol_short, token, Address, BytesN,
+    Env, IntoVal, String, Symbol, TryFromVal, Val, Vec
 };
 
 // Import your NFT contract client here (ensure correct path)
+#[allow(dead_code)] // generated client; only `Client` and the shared types are used
 mod nft_client;
-use nft_client::Client as NFTContractClient;
+use nft_client::{Client as NFTContractClient, Royalty};
 
-const ROYALTY_BPS: i128 = 1000; // 10% royalty for platform
+const DEFAULT_ROYALTY_BPS: u32 = 1000; // 10% royalty for platform
+const MAX_ROYALTY_BPS: u32 = 5000; // upper bound for any configured rate
+const DEFAULT_RESALE_ROYALTY_BPS: u32 = 500; // creator's cut when a purchased NFT is resold
 const BASIS_POINTS: i128 = 10000;
-const MIN_XLM: i128 = 1_0000000; // 1 XLM (stroops)
+const SUBSCRIPTION_PERIOD_SECS: u64 = 30 * 24 * 60 * 60; // one billing period (30 days)
+const MIN_AMOUNT: i128 = 1_0000000; // 1 unit of a 7-decimal asset (1 XLM, 1 USDC)
+const MAX_ALLOWANCES: u32 = 20; // per user, keeps the allowance list in one small entry
+const HISTORY_PAGE_SIZE: u32 = 20; // entries per persistent history page
+const MAX_SPLIT_RECIPIENTS: u32 = 10;
+const MAX_TREASURY_RECIPIENTS: u32 = 10;
+// Each spend writes ~9 ledger entries (balances, both histories, treasury, receipt, allowances,
+// nonce) and more with a revenue split; two stay inside the 25-entry per-transaction write limit
+const MAX_BATCH_SPENDS: u32 = 2;
+const MAX_LEGACY_BALANCES: u32 = 20; // one balance write each, plus the owner's pay-in
+const HOLD_BUCKET_LEDGERS: u32 = 720; // pending credits unlock on ~1 hour boundaries
+const MAX_HOLD_LEDGERS: u32 = 14 * DAY_IN_LEDGERS; // well inside the persistent bump
+
+// Storage layout version; bump it and add a step to `migrate_from` whenever the layout changes
+const STORAGE_VERSION: u32 = 1;
+
+// Instance storage (config, counters) is bumped by every entrypoint so the contract never
+// archives while in use
+const DAY_IN_LEDGERS: u32 = 17280; // ~5s per ledger
+const INSTANCE_BUMP_AMOUNT: u32 = 7 * DAY_IN_LEDGERS;
+const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+// Per-user entries live in persistent storage and are bumped on every read and write
+const PERSISTENT_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
 
 #[derive(Clone)]
 #[contracttype]
 pub enum DataKey {
     Owner,
-    Balance(Address),
+    PendingOwner,
+    Paused,
+    Version,
+    Balance(Address /* user */, Address /* asset */),
     NftContract,
+    Assets,
+    RoyaltyBps,
+    CreatorRoyaltyBps(Address),
+    ResaleRoyaltyBps,
+    SubscriptionPlan(Address /* creator */),
+    Subscription(Address /* user */, Address /* creator */),
+    Allowances(Address /* user */),
+    IdempotencyWindow,
+    SpendNonce(Address /* user */, BytesN<32>),
+    ReceiptCount,
+    Receipt(u64),
+    HistoryLen(Address),
+    HistoryPage(Address, u32 /* page */),
+    Splits(Address /* creator */),
+    HoldPeriod,
+    Pending(Address /* user */, Address /* asset */),
+    Treasury,
+    TreasuryBalance(Address /* asset */),
+}
+
+// Keys written by unversioned deployments; only migrate_from reads them
+#[derive(Clone)]
+#[contracttype(export = false)]
+enum LegacyDataKey {
+    Balance(Address), // simulated balance in instance storage, never token-backed
+}
+
+// Recipient of platform fees; each sweep pays `weight / total weight` of the pool
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct TreasuryShare {
+    pub recipient: Address,
+    pub weight: u32,
+}
+
+// Share of a creator's earnings paid to one collaborator
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct SplitShare {
+    pub recipient: Address,
+    pub bps: u32,
+}
+
+// Earnings that unlock for withdrawal at `unlock_ledger`
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct PendingCredit {
+    pub amount: i128,
+    pub unlock_ledger: u32,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct BalanceBreakdown {
+    pub available: i128,
+    pub pending: i128,
+    pub next_unlock_ledger: Option<u32>,
+}
+
+// One entry of an admin_spend_batch call; fields mirror admin_spend's arguments
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct SpendItem {
+    pub from_user: Address,
+    pub to_creator: Address,
+    pub asset: Address,
+    pub amount: i128,
+    pub tx_type: String,
+    pub product_id: Option<String>,
+    pub nonce: BytesN<32>,
+}
+
+// Per-item result of a batch; `Failed` carries the `Error` code
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum SpendResult {
+    Applied(SpendOutcome),
+    Failed(u32),
+}
+
+// Amount actually credited to one recipient of a charge
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Payout {
+    pub recipient: Address,
+    pub amount: i128,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum HistoryKind {
+    Deposit,
+    Withdraw,
+    Spend,      // paid by this address
+    Earning,    // creator share received by this address
+    Mint,
+    Refund,     // refund received
+    Chargeback, // creator share returned for a refund
+}
+
+// One line of an address's on-chain activity
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct HistoryEntry {
+    pub kind: HistoryKind,
+    pub counterparty: Option<Address>,
+    pub asset: Option<Address>,
+    pub amount: i128,
+    pub reference: Option<u64>, // receipt id, or token id for mints
+    pub timestamp: u64,
+}
+
+// How a spend was split; returned again when a spend is retried with the same nonce
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct SpendOutcome {
+    pub receipt_id: u64,
+    pub creator_amount: i128,
+    pub platform_amount: i128,
+}
+
+// Record of one charge, kept so it can be refunded later
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Receipt {
+    pub id: u64,
+    pub from: Address,
+    pub creator: Address,
+    pub asset: Address,
+    pub amount: i128,
+    pub creator_amount: i128,
+    pub platform_amount: i128,
+    pub payouts: Vec<Payout>, // creator_amount as split between the creator's recipients
+    pub tx_type: String,
+    pub product_id: Option<String>,
+    pub refunded: i128,
+}
+
+// Cap a user grants on what admin_spend may take from them.
+// `creator: None` is a global allowance covering any creator without its own entry.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Allowance {
+    pub creator: Option<Address>,
+    pub asset: Address,
+    pub amount: i128,
+    pub expiration_ledger: u32,
+}
+
+// Price a creator charges per subscription period
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct SubscriptionPlan {
+    pub asset: Address,
+    pub price: i128,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Subscription {
+    pub expires_at: u64, // ledger timestamp (seconds)
+}
+
+// Error codes are part of the public interface; never renumber existing variants
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    AmountBelowMinimum = 3,
+    InsufficientBalance = 4,
+    BalanceNotFound = 5,
+    InvalidRoyaltyBps = 6,
+    AssetNotAllowed = 7,
+    AssetAlreadyAllowed = 8,
+    NoSubscriptionPlan = 9,
+    InvalidPeriods = 10,
+    NoPendingOwner = 11,
+    ContractPaused = 12,
+    AllowanceMissing = 13,
+    AllowanceExhausted = 14,
+    InvalidExpiration = 15,
+    TooManyAllowances = 16,
+    InvalidWindow = 17,
+    ReceiptNotFound = 18,
+    RefundExceedsCharge = 19,
+    InvalidSplits = 20,
+    InvalidHoldPeriod = 21,
+    InvalidBatchSize = 22,
+    InvalidTreasury = 23,
+    AmountOverflow = 24,
 }
 
 #[contract]
@@ -30,118 +252,1347 @@ pub struct PlatformContract;
 
 #[contractimpl]
 impl PlatformContract {
-    // Set up contract owner and NFT contract address
-    pub fn initialize(env: Env, owner: Address, nft_contract_id: Address) {
+    // Set up contract owner, NFT contract address and the first allowed asset (e.g. native XLM)
+    pub fn initialize(
+        env: Env,
+        owner: Address,
+        nft_contract_id: Address,
+        asset: Address,
+    ) -> Result<(), Error> {
+        extend_instance(&env);
         if env.storage().instance().has(&DataKey::Owner) {
-            panic!("Already initialized");
+            return Err(Error::AlreadyInitialized);
         }
         env.storage().instance().set(&DataKey::Owner, &owner);
         env.storage().instance().set(&DataKey::NftContract, &nft_contract_id);
+        env.storage().instance().set(&DataKey::Assets, &Vec::from_array(&env, [asset]));
+        env.storage().instance().set(&DataKey::Version, &STORAGE_VERSION);
+        Ok(())
+    }
+
+    // Replace the contract code in place; call migrate afterwards
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), Error> {
+        extend_instance(&env);
+        read_owner(&env)?.require_auth();
+        env.deployer().update_current_contract_wasm(new_wasm_hash.clone());
+        env.events().publish((symbol_short!("upgraded"),), new_wasm_hash);
+        Ok(())
+    }
+
+    // Bring stored data up to STORAGE_VERSION and return the resulting version; a no-op when
+    // already current. Unversioned deployments must first pass their balance holders in
+    // `legacy_users`, a batch per call, then call once with none to finish.
+    pub fn migrate(env: Env, legacy_users: Vec<Address>) -> Result<u32, Error> {
+        extend_instance(&env);
+        read_owner(&env)?.require_auth();
+        let from_version = read_version(&env);
+        let mut version = from_version;
+        while version < STORAGE_VERSION && migrate_from(&env, version, &legacy_users)? {
+            version += 1;
+        }
+        if version != from_version {
+            env.storage().instance().set(&DataKey::Version, &version);
+            env.events().publish((symbol_short!("migrated"),), (from_version, version));
+        }
+        Ok(version)
+    }
+
+    // Storage layout version of the stored data
+    pub fn version(env: Env) -> Result<u32, Error> {
+        extend_instance(&env);
+        Ok(read_version(&env))
+    }
+
+    pub fn get_owner(env: Env) -> Result<Address, Error> {
+        extend_instance(&env);
+        read_owner(&env)
+    }
+
+    pub fn get_pending_owner(env: Env) -> Result<Option<Address>, Error> {
+        extend_instance(&env);
+        Ok(env.storage().instance().get(&DataKey::PendingOwner))
+    }
+
+    // Step one of an ownership transfer; takes effect once the new owner accepts
+    pub fn propose_owner(env: Env, new_owner: Address) -> Result<(), Error> {
+        extend_instance(&env);
+        let owner = read_owner(&env)?;
+        owner.require_auth();
+        env.storage().instance().set(&DataKey::PendingOwner, &new_owner);
+        env.events().publish((symbol_short!("own_prop"), owner), new_owner);
+        Ok(())
+    }
+
+    // Step two: the proposed owner signs to take over
+    pub fn accept_ownership(env: Env) -> Result<(), Error> {
+        extend_instance(&env);
+        let old_owner = read_owner(&env)?;
+        let new_owner: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::PendingOwner)
+            .ok_or(Error::NoPendingOwner)?;
+        new_owner.require_auth();
+        env.storage().instance().set(&DataKey::Owner, &new_owner);
+        env.storage().instance().remove(&DataKey::PendingOwner);
+        env.events().publish((symbol_short!("own_acc"), old_owner), new_owner);
+        Ok(())
+    }
+
+    pub fn cancel_ownership_transfer(env: Env) -> Result<(), Error> {
+        extend_instance(&env);
+        let owner = read_owner(&env)?;
+        owner.require_auth();
+        let pending: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::PendingOwner)
+            .ok_or(Error::NoPendingOwner)?;
+        env.storage().instance().remove(&DataKey::PendingOwner);
+        env.events().publish((symbol_short!("own_cncl"), owner), pending);
+        Ok(())
+    }
+
+    // Halt money paths; functions named in `allowed` (e.g. "withdraw") keep working
+    pub fn pause(env: Env, allowed: Vec<Symbol>) -> Result<(), Error> {
+        extend_instance(&env);
+        read_owner(&env)?.require_auth();
+        env.storage().instance().set(&DataKey::Paused, &allowed);
+        env.events().publish((symbol_short!("paused"),), allowed);
+        Ok(())
+    }
+
+    pub fn unpause(env: Env) -> Result<(), Error> {
+        extend_instance(&env);
+        read_owner(&env)?.require_auth();
+        env.storage().instance().remove(&DataKey::Paused);
+        env.events().publish((symbol_short!("unpaused"),), ());
+        Ok(())
+    }
+
+    pub fn is_paused(env: Env) -> Result<bool, Error> {
+        extend_instance(&env);
+        Ok(env.storage().instance().has(&DataKey::Paused))
+    }
+
+    // Functions that stay available while paused (empty when not paused)
+    pub fn get_pause_allowlist(env: Env) -> Result<Vec<Symbol>, Error> {
+        extend_instance(&env);
+        Ok(env
+            .storage()
+            .instance()
+            .get(&DataKey::Paused)
+            .unwrap_or_else(|| Vec::new(&env)))
+    }
+
+    // Allow deposits and spends in another Stellar Asset Contract
+    pub fn add_asset(env: Env, asset: Address) -> Result<(), Error> {
+        extend_instance(&env);
+        read_owner(&env)?.require_auth();
+        let mut assets = read_assets(&env);
+        if assets.contains(&asset) {
+            return Err(Error::AssetAlreadyAllowed);
+        }
+        assets.push_back(asset.clone());
+        env.storage().instance().set(&DataKey::Assets, &assets);
+        env.events().publish((symbol_short!("asset_add"), asset), ());
+        Ok(())
+    }
+
+    // Stop accepting an asset; existing balances can still be withdrawn
+    pub fn remove_asset(env: Env, asset: Address) -> Result<(), Error> {
+        extend_instance(&env);
+        read_owner(&env)?.require_auth();
+        let mut assets = read_assets(&env);
+        let index = assets.first_index_of(&asset).ok_or(Error::AssetNotAllowed)?;
+        assets.remove(index);
+        env.storage().instance().set(&DataKey::Assets, &assets);
+        env.events().publish((symbol_short!("asset_rm"), asset), ());
+        Ok(())
+    }
+
+    // Get the Stellar Asset Contracts that balances can be held in
+    pub fn get_assets(env: Env) -> Result<Vec<Address>, Error> {
+        extend_instance(&env);
+        Ok(read_assets(&env))
+    }
+
+    pub fn is_asset_allowed(env: Env, asset: Address) -> Result<bool, Error> {
+        extend_instance(&env);
+        Ok(read_assets(&env).contains(&asset))
     }
 
-    // Simulated deposit (backend or future on-chain transfer)
-    pub fn deposit(env: Env, user: Address, amount: i128) {
+    // Deposit: pull tokens from the user into the contract and credit their balance
+    pub fn deposit(env: Env, user: Address, asset: Address, amount: i128) -> Result<(), Error> {
+        extend_instance(&env);
         user.require_auth();
-        if amount < MIN_XLM {
-            panic!("Minimum deposit is 1 XLM");
+        require_not_paused(&env, "deposit")?;
+        require_allowed_asset(&env, &asset)?;
+        if amount < MIN_AMOUNT {
+            return Err(Error::AmountBelowMinimum);
         }
-        let key = DataKey::Balance(user.clone());
-        let mut bal: i128 = env.storage().instance().get(&key).unwrap_or(0);
-        bal += amount;
-        env.storage().instance().set(&key, &bal);
+        token::Client::new(&env, &asset).transfer(
+            &user,
+            &env.current_contract_address(),
+            &amount,
+        );
+
+        credit_balance(&env, &user, &asset, amount);
+        record_history(&env, &user, HistoryKind::Deposit, None, Some(asset.clone()), amount, None);
         env.events().publish(
             (symbol_short!("deposit"), user.clone()),
-            amount,
+            (asset, amount),
         );
+        Ok(())
+    }
+
+    // Query user balance in one asset
+    // Withdrawable balance, including held earnings that have since unlocked
+    pub fn get_balance(env: Env, user: Address, asset: Address) -> Result<i128, Error> {
+        extend_instance(&env);
+        let (unlocked, _) = split_pending(&env, &read_pending(&env, &user, &asset));
+        Ok(read_balance(&env, &user, &asset) + unlocked)
+    }
+
+    // Available and still-held amounts side by side
+    pub fn get_balance_breakdown(
+        env: Env,
+        user: Address,
+        asset: Address,
+    ) -> Result<BalanceBreakdown, Error> {
+        extend_instance(&env);
+        let pending = read_pending(&env, &user, &asset);
+        let (unlocked, held) = split_pending(&env, &pending);
+        let next_unlock_ledger = pending
+            .iter()
+            .map(|credit| credit.unlock_ledger)
+            .find(|ledger| *ledger > env.ledger().sequence());
+        Ok(BalanceBreakdown {
+            available: read_balance(&env, &user, &asset) + unlocked,
+            pending: held,
+            next_unlock_ledger,
+        })
     }
 
-    // Query user balance
-    pub fn get_balance(env: Env, user: Address) -> i128 {
-        env.storage().instance().get(&DataKey::Balance(user)).unwrap_or(0)
+    // Keep a user's balance entry alive without touching it
+    pub fn extend_balance_ttl(env: Env, user: Address, asset: Address) -> Result<(), Error> {
+        extend_instance(&env);
+        let key = DataKey::Balance(user, asset);
+        if !env.storage().persistent().has(&key) {
+            return Err(Error::BalanceNotFound);
+        }
+        extend_persistent(&env, &key);
+        Ok(())
+    }
+
+    // Set the platform royalty applied to creators without an override
+    pub fn set_royalty_bps(env: Env, bps: u32) -> Result<(), Error> {
+        extend_instance(&env);
+        read_owner(&env)?.require_auth();
+        if bps > MAX_ROYALTY_BPS {
+            return Err(Error::InvalidRoyaltyBps);
+        }
+        env.storage().instance().set(&DataKey::RoyaltyBps, &bps);
+        env.events().publish((symbol_short!("royalty"),), bps);
+        Ok(())
+    }
+
+    // Set or clear (None) a negotiated royalty rate for one creator
+    pub fn set_creator_royalty_bps(
+        env: Env,
+        creator: Address,
+        bps: Option<u32>,
+    ) -> Result<(), Error> {
+        extend_instance(&env);
+        read_owner(&env)?.require_auth();
+        let key = DataKey::CreatorRoyaltyBps(creator.clone());
+        match bps {
+            Some(bps) => {
+                if bps > MAX_ROYALTY_BPS {
+                    return Err(Error::InvalidRoyaltyBps);
+                }
+                write_persistent(&env, &key, &bps);
+            }
+            None => env.storage().persistent().remove(&key),
+        }
+        env.events().publish((symbol_short!("royalty"), creator), bps);
+        Ok(())
+    }
+
+    // Set the resale royalty written into NFTs bought through purchase_nft; 0 mints without one
+    pub fn set_resale_royalty_bps(env: Env, bps: u32) -> Result<(), Error> {
+        extend_instance(&env);
+        read_owner(&env)?.require_auth();
+        if bps > MAX_ROYALTY_BPS {
+            return Err(Error::InvalidRoyaltyBps);
+        }
+        env.storage().instance().set(&DataKey::ResaleRoyaltyBps, &bps);
+        env.events().publish((symbol_short!("resale"),), bps);
+        Ok(())
     }
 
-    // Spend (admin action) - tips, subs, NFT buys; splits royalty
+    // Royalty rate (basis points) the creator earns when a purchased NFT is resold
+    pub fn get_resale_royalty_bps(env: Env) -> Result<u32, Error> {
+        extend_instance(&env);
+        Ok(read_resale_royalty_bps(&env))
+    }
+
+    // Royalty rate (basis points) the platform takes from spends to this creator
+    pub fn get_royalty_bps(env: Env, creator: Address) -> Result<u32, Error> {
+        extend_instance(&env);
+        Ok(read_royalty_bps(&env, &creator))
+    }
+
+    // Spend (admin action) - tips, subs, NFT buys; splits royalty.
+    // `nonce` is chosen by the caller per logical spend; a retry with the same
+    // nonce inside the idempotency window returns the first outcome without charging again.
+    #[allow(clippy::too_many_arguments)]
     pub fn admin_spend(
         env: Env,
         from_user: Address,
         to_creator: Address,
+        asset: Address,
         amount: i128,
-        tx_type: SorobanString,
-        product_id: Option<SorobanString>,
-    ) {
-        let owner: Address = env.storage().instance().get(&DataKey::Owner).unwrap();
+        tx_type: String,
+        product_id: Option<String>,
+        nonce: BytesN<32>,
+    ) -> Result<SpendOutcome, Error> {
+        extend_instance(&env);
+        read_owner(&env)?.require_auth();
+        let item = SpendItem {
+            from_user,
+            to_creator,
+            asset,
+            amount,
+            tx_type,
+            product_id,
+            nonce,
+        };
+        apply_spend(&env, &item)
+    }
+
+    // Settle many spends in one call. With `atomic` the first failing item aborts the whole
+    // batch; otherwise failed items are reported and the rest still apply.
+    pub fn admin_spend_batch(
+        env: Env,
+        items: Vec<SpendItem>,
+        atomic: bool,
+    ) -> Result<Vec<SpendResult>, Error> {
+        extend_instance(&env);
+        read_owner(&env)?.require_auth();
+        if items.is_empty() || items.len() > MAX_BATCH_SPENDS {
+            return Err(Error::InvalidBatchSize);
+        }
+        let mut results = Vec::new(&env);
+        for item in items.iter() {
+            let result = match apply_spend(&env, &item) {
+                Ok(outcome) => SpendResult::Applied(outcome),
+                Err(error) if !atomic => SpendResult::Failed(error as u32),
+                Err(error) => return Err(error),
+            };
+            results.push_back(result);
+        }
+        Ok(results)
+    }
+
+    // Return part or all of a charge, taken back from creator and platform in proportion
+    pub fn refund(env: Env, receipt_id: u64, amount: i128) -> Result<Receipt, Error> {
+        extend_instance(&env);
+        let owner = read_owner(&env)?;
         owner.require_auth();
+        require_not_paused(&env, "refund")?;
+        let key = DataKey::Receipt(receipt_id);
+        let mut receipt: Receipt = read_persistent(&env, &key).ok_or(Error::ReceiptNotFound)?;
+        if amount <= 0 {
+            return Err(Error::AmountBelowMinimum);
+        }
+        if amount > receipt.amount - receipt.refunded {
+            return Err(Error::RefundExceedsCharge);
+        }
 
-        if amount < MIN_XLM {
-            panic!("Min spend 1 XLM");
+        // Split cumulatively so a full refund returns exactly what was credited
+        let before = receipt.refunded;
+        let share = |credited: i128, refunded: i128| refunded * credited / receipt.amount;
+        let asset = Some(receipt.asset.clone());
+        let mut creator_part = 0;
+        for payout in receipt.payouts.iter() {
+            let part = share(payout.amount, before + amount) - share(payout.amount, before);
+            debit_earnings(&env, &payout.recipient, &receipt.asset, part)?;
+            record_history(
+                &env,
+                &payout.recipient,
+                HistoryKind::Chargeback,
+                Some(receipt.from.clone()),
+                asset.clone(),
+                part,
+                Some(receipt_id),
+            );
+            creator_part += part;
         }
-        let from_key = DataKey::Balance(from_user.clone());
-        let mut from_bal: i128 = env.storage().instance().get(&from_key).unwrap_or(0);
-        if from_bal < amount {
-            panic!("Insufficient balance");
+        let platform_part = amount - creator_part;
+
+        debit_treasury(&env, &owner, &receipt.asset, platform_part);
+        credit_balance(&env, &receipt.from, &receipt.asset, amount);
+        receipt.refunded += amount;
+        write_persistent(&env, &key, &receipt);
+
+        record_history(
+            &env,
+            &receipt.from,
+            HistoryKind::Refund,
+            Some(receipt.creator.clone()),
+            asset,
+            amount,
+            Some(receipt_id),
+        );
+
+        env.events().publish(
+            (symbol_short!("refund"), receipt.from.clone(), receipt.creator.clone()),
+            (receipt_id, receipt.asset.clone(), amount),
+        );
+        Ok(receipt)
+    }
+
+    pub fn get_receipt(env: Env, receipt_id: u64) -> Result<Receipt, Error> {
+        extend_instance(&env);
+        read_persistent(&env, &DataKey::Receipt(receipt_id)).ok_or(Error::ReceiptNotFound)
+    }
+
+    // One page (oldest first, HISTORY_PAGE_SIZE entries) of an address's activity
+    pub fn get_history(env: Env, address: Address, page: u32) -> Result<Vec<HistoryEntry>, Error> {
+        extend_instance(&env);
+        Ok(read_persistent(&env, &DataKey::HistoryPage(address, page))
+            .unwrap_or_else(|| Vec::new(&env)))
+    }
+
+    // Total number of history entries for an address
+    pub fn get_history_len(env: Env, address: Address) -> Result<u32, Error> {
+        extend_instance(&env);
+        Ok(read_persistent(&env, &DataKey::HistoryLen(address)).unwrap_or(0))
+    }
+
+    // Minimum ledgers new creator earnings are held before they can be withdrawn; 0 disables holds.
+    // Credits unlock at the next HOLD_BUCKET_LEDGERS boundary after the period ends, so a hold
+    // can run up to 719 ledgers (~1 hour) longer than configured
+    pub fn set_hold_period(env: Env, ledgers: u32) -> Result<(), Error> {
+        extend_instance(&env);
+        read_owner(&env)?.require_auth();
+        if ledgers > MAX_HOLD_LEDGERS {
+            return Err(Error::InvalidHoldPeriod);
+        }
+        env.storage().instance().set(&DataKey::HoldPeriod, &ledgers);
+        env.events().publish((symbol_short!("hold"),), ledgers);
+        Ok(())
+    }
+
+    // The configured minimum hold; actual unlocks round up to the next ~1 hour bucket
+    pub fn get_hold_period(env: Env) -> Result<u32, Error> {
+        extend_instance(&env);
+        Ok(read_hold_period(&env))
+    }
+
+    // Owner pulls back earnings that are still on hold, e.g. after a fraud report
+    pub fn claw_back(
+        env: Env,
+        creator: Address,
+        asset: Address,
+        amount: i128,
+        to: Address,
+    ) -> Result<(), Error> {
+        extend_instance(&env);
+        read_owner(&env)?.require_auth();
+        if amount <= 0 {
+            return Err(Error::AmountBelowMinimum);
+        }
+        settle_pending(&env, &creator, &asset);
+        if take_pending(&env, &creator, &asset, amount) < amount {
+            return Err(Error::InsufficientBalance);
+        }
+        credit_balance(&env, &to, &asset, amount);
+        record_history(
+            &env,
+            &creator,
+            HistoryKind::Chargeback,
+            Some(to.clone()),
+            Some(asset.clone()),
+            amount,
+            None,
+        );
+        record_history(
+            &env,
+            &to,
+            HistoryKind::Refund,
+            Some(creator.clone()),
+            Some(asset.clone()),
+            amount,
+            None,
+        );
+        env.events().publish((symbol_short!("clawback"), creator), (asset, amount, to));
+        Ok(())
+    }
+
+    // How many ledgers admin_spend remembers processed nonces
+    pub fn set_idempotency_window(env: Env, ledgers: u32) -> Result<(), Error> {
+        extend_instance(&env);
+        read_owner(&env)?.require_auth();
+        // Temporary entries cannot be extended past the network's max TTL
+        if ledgers == 0 || ledgers > env.storage().max_ttl() {
+            return Err(Error::InvalidWindow);
         }
-        from_bal -= amount;
-        env.storage().instance().set(&from_key, &from_bal);
+        env.storage().instance().set(&DataKey::IdempotencyWindow, &ledgers);
+        env.events().publish((symbol_short!("idem_win"),), ledgers);
+        Ok(())
+    }
 
-        let creator_amt = amount * (BASIS_POINTS - ROYALTY_BPS) / BASIS_POINTS;
-        let platform_amt = amount - creator_amt;
+    pub fn get_idempotency_window(env: Env) -> Result<u32, Error> {
+        extend_instance(&env);
+        Ok(read_idempotency_window(&env))
+    }
 
-        // Credit creator
-        let creator_key = DataKey::Balance(to_creator.clone());
-        let mut creator_bal: i128 = env.storage().instance().get(&creator_key).unwrap_or(0);
-        creator_bal += creator_amt;
-        env.storage().instance().set(&creator_key, &creator_bal);
+    // Creator shares future earnings between recipients; shares must add up to 100%
+    pub fn set_splits(env: Env, creator: Address, shares: Vec<SplitShare>) -> Result<(), Error> {
+        extend_instance(&env);
+        creator.require_auth();
+        if shares.is_empty() || shares.len() > MAX_SPLIT_RECIPIENTS {
+            return Err(Error::InvalidSplits);
+        }
+        let mut total: i128 = 0;
+        for (i, share) in shares.iter().enumerate() {
+            let duplicate = shares
+                .iter()
+                .skip(i + 1)
+                .any(|other| other.recipient == share.recipient);
+            if share.bps == 0 || duplicate {
+                return Err(Error::InvalidSplits);
+            }
+            total += share.bps as i128;
+        }
+        if total != BASIS_POINTS {
+            return Err(Error::InvalidSplits);
+        }
+        write_persistent(&env, &DataKey::Splits(creator.clone()), &shares);
+        env.events().publish((symbol_short!("splits"), creator), shares);
+        Ok(())
+    }
 
-        // Credit platform/owner
-        let platform_key = DataKey::Balance(owner.clone());
-        let mut plat_bal: i128 = env.storage().instance().get(&platform_key).unwrap_or(0);
-        plat_bal += platform_amt;
-        env.storage().instance().set(&platform_key, &plat_bal);
+    // Go back to crediting the creator's whole share to the creator
+    pub fn clear_splits(env: Env, creator: Address) -> Result<(), Error> {
+        extend_instance(&env);
+        creator.require_auth();
+        env.storage().persistent().remove(&DataKey::Splits(creator.clone()));
+        env.events().publish((symbol_short!("splits"), creator), Vec::<SplitShare>::new(&env));
+        Ok(())
+    }
+
+    // Split table for a creator; empty when earnings all go to the creator
+    pub fn get_splits(env: Env, creator: Address) -> Result<Vec<SplitShare>, Error> {
+        extend_instance(&env);
+        Ok(read_persistent(&env, &DataKey::Splits(creator)).unwrap_or_else(|| Vec::new(&env)))
+    }
+
+    // Where platform fees are paid out on sweep; replaces the whole recipient list
+    pub fn set_treasury(env: Env, recipients: Vec<TreasuryShare>) -> Result<(), Error> {
+        extend_instance(&env);
+        read_owner(&env)?.require_auth();
+        if recipients.is_empty() || recipients.len() > MAX_TREASURY_RECIPIENTS {
+            return Err(Error::InvalidTreasury);
+        }
+        for (i, share) in recipients.iter().enumerate() {
+            let duplicate = recipients
+                .iter()
+                .skip(i + 1)
+                .any(|other| other.recipient == share.recipient);
+            if share.weight == 0 || duplicate {
+                return Err(Error::InvalidTreasury);
+            }
+        }
+        env.storage().instance().set(&DataKey::Treasury, &recipients);
+        env.events().publish((symbol_short!("treasury"),), recipients);
+        Ok(())
+    }
+
+    // Treasury recipients; defaults to the owner alone until one is set
+    pub fn get_treasury(env: Env) -> Result<Vec<TreasuryShare>, Error> {
+        extend_instance(&env);
+        read_treasury(&env)
+    }
+
+    // Platform fees collected in `asset` and not yet swept
+    pub fn get_treasury_balance(env: Env, asset: Address) -> Result<i128, Error> {
+        extend_instance(&env);
+        Ok(read_persistent(&env, &DataKey::TreasuryBalance(asset)).unwrap_or(0))
+    }
+
+    // Pay accumulated fees out to the treasury recipients by weight; returns the amount swept
+    pub fn sweep_treasury(env: Env, asset: Address) -> Result<i128, Error> {
+        extend_instance(&env);
+        read_owner(&env)?.require_auth();
+        require_not_paused(&env, "sweep_treasury")?;
+        let recipients = read_treasury(&env)?;
+        let key = DataKey::TreasuryBalance(asset.clone());
+        let amount: i128 = read_persistent(&env, &key).unwrap_or(0);
+        if amount == 0 {
+            return Ok(0);
+        }
+        env.storage().persistent().remove(&key);
+
+        let total_weight: i128 = recipients.iter().map(|share| share.weight as i128).sum();
+        let token = token::Client::new(&env, &asset);
+        let mut remaining = amount;
+        // Rounding dust goes to the first recipient, paid last
+        for share in recipients.iter().skip(1) {
+            let part = amount * share.weight as i128 / total_weight;
+            remaining -= part;
+            if part > 0 {
+                token.transfer(&env.current_contract_address(), &share.recipient, &part);
+            }
+        }
+        let first = recipients.get_unchecked(0).recipient;
+        token.transfer(&env.current_contract_address(), &first, &remaining);
+
+        env.events().publish((symbol_short!("sweep"), asset), amount);
+        Ok(amount)
+    }
+
+    // User caps what the platform may charge them, for one creator or (None) globally.
+    // Replaces any existing allowance for the same creator and asset.
+    pub fn approve_spending(
+        env: Env,
+        user: Address,
+        creator: Option<Address>,
+        asset: Address,
+        amount: i128,
+        expiration_ledger: u32,
+    ) -> Result<(), Error> {
+        extend_instance(&env);
+        user.require_auth();
+        if amount < 0 {
+            return Err(Error::AmountBelowMinimum);
+        }
+        if expiration_ledger < env.ledger().sequence() {
+            return Err(Error::InvalidExpiration);
+        }
+        let allowance = Allowance {
+            creator: creator.clone(),
+            asset: asset.clone(),
+            amount,
+            expiration_ledger,
+        };
+        let mut allowances = read_allowances(&env, &user);
+        match position_of_allowance(&allowances, &creator, &asset) {
+            Some(index) => allowances.set(index, allowance),
+            None => {
+                if allowances.len() >= MAX_ALLOWANCES {
+                    return Err(Error::TooManyAllowances);
+                }
+                allowances.push_back(allowance);
+            }
+        }
+        write_persistent(&env, &DataKey::Allowances(user.clone()), &allowances);
+        env.events().publish(
+            (symbol_short!("allow"), user),
+            (creator, asset, amount, expiration_ledger),
+        );
+        Ok(())
+    }
+
+    pub fn revoke_spending(
+        env: Env,
+        user: Address,
+        creator: Option<Address>,
+        asset: Address,
+    ) -> Result<(), Error> {
+        extend_instance(&env);
+        user.require_auth();
+        let mut allowances = read_allowances(&env, &user);
+        let index = position_of_allowance(&allowances, &creator, &asset)
+            .ok_or(Error::AllowanceMissing)?;
+        allowances.remove(index);
+        write_persistent(&env, &DataKey::Allowances(user.clone()), &allowances);
+        env.events().publish((symbol_short!("allow_rm"), user), (creator, asset));
+        Ok(())
+    }
+
+    // All allowances a user has granted, including expired or used-up ones
+    pub fn get_allowances(env: Env, user: Address) -> Result<Vec<Allowance>, Error> {
+        extend_instance(&env);
+        Ok(read_allowances(&env, &user))
+    }
+
+    // Tip signed by the fan; same royalty split as admin_spend
+    pub fn tip(
+        env: Env,
+        from: Address,
+        to_creator: Address,
+        asset: Address,
+        amount: i128,
+        memo: String,
+    ) -> Result<u64, Error> {
+        extend_instance(&env);
+        from.require_auth();
+        require_not_paused(&env, "tip")?;
+        let tx_type = String::from_str(&env, "tip");
+        let outcome = charge(&env, &from, &to_creator, &asset, amount, &tx_type, &None)?;
+
+        env.events().publish(
+            (symbol_short!("tipped"), from.clone(), to_creator.clone()),
+            (asset, amount, memo, outcome.receipt_id),
+        );
+        Ok(outcome.receipt_id)
+    }
+
+    // Creator sets the price of one subscription period
+    pub fn set_subscription_plan(
+        env: Env,
+        creator: Address,
+        asset: Address,
+        price: i128,
+    ) -> Result<(), Error> {
+        extend_instance(&env);
+        creator.require_auth();
+        require_allowed_asset(&env, &asset)?;
+        if price < MIN_AMOUNT {
+            return Err(Error::AmountBelowMinimum);
+        }
+        let plan = SubscriptionPlan { asset, price };
+        write_persistent(&env, &DataKey::SubscriptionPlan(creator.clone()), &plan);
+        env.events().publish(
+            (symbol_short!("sub_plan"), creator),
+            (plan.asset, plan.price),
+        );
+        Ok(())
+    }
+
+    pub fn get_subscription_plan(env: Env, creator: Address) -> Result<SubscriptionPlan, Error> {
+        extend_instance(&env);
+        read_persistent(&env, &DataKey::SubscriptionPlan(creator)).ok_or(Error::NoSubscriptionPlan)
+    }
+
+    // Pay for `periods` billing periods from the app balance; renewals extend the current expiry
+    pub fn subscribe(
+        env: Env,
+        user: Address,
+        creator: Address,
+        periods: u32,
+    ) -> Result<u64, Error> {
+        extend_instance(&env);
+        user.require_auth();
+        require_not_paused(&env, "subscribe")?;
+        if periods == 0 {
+            return Err(Error::InvalidPeriods);
+        }
+        let plan = Self::get_subscription_plan(env.clone(), creator.clone())?;
+        let amount = plan
+            .price
+            .checked_mul(periods as i128)
+            .ok_or(Error::AmountOverflow)?;
+        let tx_type = String::from_str(&env, "subscription");
+        let outcome = charge(&env, &user, &creator, &plan.asset, amount, &tx_type, &None)?;
+
+        let key = DataKey::Subscription(user.clone(), creator.clone());
+        let now = env.ledger().timestamp();
+        let starts_at = match read_persistent::<Subscription>(&env, &key) {
+            Some(current) if current.expires_at > now => current.expires_at,
+            _ => now,
+        };
+        let expires_at = starts_at + periods as u64 * SUBSCRIPTION_PERIOD_SECS;
+        write_persistent(&env, &key, &Subscription { expires_at });
 
         env.events().publish(
-            (symbol_short!("spend"), from_user.clone(), to_creator.clone(), tx_type.clone()),
-            (amount, product_id),
+            (Symbol::new(&env, "subscribed"), user, creator),
+            (plan.asset, amount, expires_at, outcome.receipt_id),
         );
+        Ok(expires_at)
+    }
+
+    pub fn is_subscribed(env: Env, user: Address, creator: Address) -> Result<bool, Error> {
+        extend_instance(&env);
+        let now = env.ledger().timestamp();
+        Ok(read_persistent::<Subscription>(&env, &DataKey::Subscription(user, creator))
+            .is_some_and(|sub| sub.expires_at > now))
+    }
+
+    pub fn get_subscription(
+        env: Env,
+        user: Address,
+        creator: Address,
+    ) -> Result<Option<Subscription>, Error> {
+        extend_instance(&env);
+        Ok(read_persistent(&env, &DataKey::Subscription(user, creator)))
     }
 
     // Mint NFT by calling external NFT contract
     pub fn mint_nft(
         env: Env,
         to: Address,
-        name: SorobanString,
-        description: SorobanString,
-        image_url: SorobanString,
-    ) -> u32 {
-        let owner: Address = env.storage().instance().get(&DataKey::Owner).unwrap();
-        owner.require_auth();
-        let nft_contract_id: Address = env.storage().instance().get(&DataKey::NftContract).unwrap();
+        name: String,
+        description: String,
+        image_url: String,
+    ) -> Result<u32, Error> {
+        extend_instance(&env);
+        read_owner(&env)?.require_auth();
+        require_not_paused(&env, "mint_nft")?;
+        mint_token(&env, &to, &name, &description, &image_url, None)
+    }
 
-        let nft_client = NFTContractClient::new(&env, &nft_contract_id);
-        let token_id = nft_client.mint(&to, &name, &description, &image_url);
+    // Charge the buyer and mint the access NFT in one transaction; returns the token id
+    #[allow(clippy::too_many_arguments)]
+    pub fn purchase_nft(
+        env: Env,
+        buyer: Address,
+        creator: Address,
+        asset: Address,
+        price: i128,
+        name: String,
+        description: String,
+        image_url: String,
+    ) -> Result<u32, Error> {
+        extend_instance(&env);
+        read_owner(&env)?.require_auth();
+        require_not_paused(&env, "purchase_nft")?;
+        let (allowances, index) = find_allowance(&env, &buyer, &creator, &asset, price)?;
+        let tx_type = String::from_str(&env, "nft");
+        let outcome = charge(&env, &buyer, &creator, &asset, price, &tx_type, &None)?;
+        spend_allowance(&env, &buyer, allowances, index, price);
+        // Resales of the pass pay the creator
+        let bps = read_resale_royalty_bps(&env);
+        let royalty = (bps > 0).then(|| Royalty { receiver: creator.clone(), bps });
+        let token_id = mint_token(&env, &buyer, &name, &description, &image_url, royalty)?;
 
         env.events().publish(
-            (symbol_short!("minted"), to.clone()),
-            token_id,
+            (symbol_short!("purchase"), buyer.clone(), creator.clone()),
+            (asset, price, token_id, outcome.receipt_id),
         );
-        token_id
+        Ok(token_id)
     }
 
-    // Simulated withdraw (real payment: see SDK 22 native asset docs)
-    pub fn withdraw(env: Env, user: Address, amount: i128) {
+    // Withdraw: debit the user's balance and pay the tokens out of the contract
+    pub fn withdraw(env: Env, user: Address, asset: Address, amount: i128) -> Result<(), Error> {
+        extend_instance(&env);
         user.require_auth();
-        if amount < MIN_XLM {
-            panic!("Min withdraw is 1 XLM");
-        }
-        let key = DataKey::Balance(user.clone());
-        let mut bal: i128 = env.storage().instance().get(&key).unwrap_or(0);
-        if bal < amount {
-            panic!("Insufficient balance");
+        require_not_paused(&env, "withdraw")?;
+        if amount < MIN_AMOUNT {
+            return Err(Error::AmountBelowMinimum);
         }
-        bal -= amount;
-        env.storage().instance().set(&key, &bal);
+        debit_balance(&env, &user, &asset, amount)?;
+        token::Client::new(&env, &asset).transfer(
+            &env.current_contract_address(),
+            &user,
+            &amount,
+        );
+        record_history(&env, &user, HistoryKind::Withdraw, None, Some(asset.clone()), amount, None);
         env.events().publish(
             (symbol_short!("withdraw"), user.clone()),
-            amount,
+            (asset, amount),
+        );
+        Ok(())
+    }
+}
+
+fn read_owner(env: &Env) -> Result<Address, Error> {
+    env.storage().instance().get(&DataKey::Owner).ok_or(Error::NotInitialized)
+}
+
+// Body of admin_spend, shared with batches. Every check runs before the first write, so a
+// failed item leaves no partial state behind in a best-effort batch.
+fn apply_spend(env: &Env, item: &SpendItem) -> Result<SpendOutcome, Error> {
+    let nonce_key = DataKey::SpendNonce(item.from_user.clone(), item.nonce.clone());
+    if let Some(outcome) = env.storage().temporary().get(&nonce_key) {
+        return Ok(outcome);
+    }
+    require_not_paused(env, "admin_spend")?;
+    let (from, creator, asset) = (&item.from_user, &item.to_creator, &item.asset);
+    let (allowances, index) = find_allowance(env, from, creator, asset, item.amount)?;
+    let outcome = charge(env, from, creator, asset, item.amount, &item.tx_type, &item.product_id)?;
+    spend_allowance(env, from, allowances, index, item.amount);
+
+    let window = read_idempotency_window(env);
+    env.storage().temporary().set(&nonce_key, &outcome);
+    env.storage().temporary().extend_ttl(&nonce_key, window, window);
+
+    env.events().publish(
+        (symbol_short!("spend"), from.clone(), creator.clone(), item.tx_type.clone()),
+        (asset.clone(), item.amount, item.product_id.clone(), outcome.receipt_id),
+    );
+    Ok(outcome)
+}
+
+// Debit `from`, split the amount between the creator and the platform and store a receipt
+fn charge(
+    env: &Env,
+    from: &Address,
+    to_creator: &Address,
+    asset: &Address,
+    amount: i128,
+    tx_type: &String,
+    product_id: &Option<String>,
+) -> Result<SpendOutcome, Error> {
+    read_owner(env)?;
+    require_allowed_asset(env, asset)?;
+    if amount < MIN_AMOUNT {
+        return Err(Error::AmountBelowMinimum);
+    }
+    debit_balance(env, from, asset, amount)?;
+
+    let royalty_bps = read_royalty_bps(env, to_creator) as i128;
+    let creator_amt = amount * (BASIS_POINTS - royalty_bps) / BASIS_POINTS;
+    let platform_amt = amount - creator_amt;
+
+    // Credit creator (or their split recipients) and the platform treasury
+    let payouts = credit_creator_share(env, to_creator, asset, creator_amt);
+    credit_treasury(env, asset, platform_amt);
+
+    let receipt_id: u64 = env.storage().instance().get(&DataKey::ReceiptCount).unwrap_or(0) + 1;
+    env.storage().instance().set(&DataKey::ReceiptCount, &receipt_id);
+    let receipt = Receipt {
+        id: receipt_id,
+        from: from.clone(),
+        creator: to_creator.clone(),
+        asset: asset.clone(),
+        amount,
+        creator_amount: creator_amt,
+        platform_amount: platform_amt,
+        payouts: payouts.clone(),
+        tx_type: tx_type.clone(),
+        product_id: product_id.clone(),
+        refunded: 0,
+    };
+    write_persistent(env, &DataKey::Receipt(receipt_id), &receipt);
+
+    record_history(
+        env,
+        from,
+        HistoryKind::Spend,
+        Some(to_creator.clone()),
+        Some(asset.clone()),
+        amount,
+        Some(receipt_id),
+    );
+    for payout in payouts.iter() {
+        record_history(
+            env,
+            &payout.recipient,
+            HistoryKind::Earning,
+            Some(from.clone()),
+            Some(asset.clone()),
+            payout.amount,
+            Some(receipt_id),
         );
     }
+
+    Ok(SpendOutcome {
+        receipt_id,
+        creator_amount: creator_amt,
+        platform_amount: platform_amt,
+    })
 }
+
+// Divide the creator's share by their split table; rounding dust goes to the first recipient
+fn credit_creator_share(
+    env: &Env,
+    creator: &Address,
+    asset: &Address,
+    amount: i128,
+) -> Vec<Payout> {
+    let shares: Vec<SplitShare> = read_persistent(env, &DataKey::Splits(creator.clone()))
+        .unwrap_or_else(|| {
+            let whole = SplitShare { recipient: creator.clone(), bps: BASIS_POINTS as u32 };
+            Vec::from_array(env, [whole])
+        });
+    let mut payouts = Vec::new(env);
+    let mut remaining = amount;
+    for share in shares.iter() {
+        let part = amount * share.bps as i128 / BASIS_POINTS;
+        remaining -= part;
+        payouts.push_back(Payout { recipient: share.recipient, amount: part });
+    }
+    let mut first = payouts.get_unchecked(0);
+    first.amount += remaining;
+    payouts.set(0, first);
+
+    for payout in payouts.iter() {
+        credit_earnings(env, &payout.recipient, asset, payout.amount);
+    }
+    payouts
+}
+
+fn read_treasury(env: &Env) -> Result<Vec<TreasuryShare>, Error> {
+    match env.storage().instance().get(&DataKey::Treasury) {
+        Some(recipients) => Ok(recipients),
+        None => {
+            let owner = TreasuryShare { recipient: read_owner(env)?, weight: 1 };
+            Ok(Vec::from_array(env, [owner]))
+        }
+    }
+}
+
+fn credit_treasury(env: &Env, asset: &Address, amount: i128) {
+    let key = DataKey::TreasuryBalance(asset.clone());
+    let balance: i128 = read_persistent(env, &key).unwrap_or(0);
+    write_persistent(env, &key, &(balance + amount));
+}
+
+// Take a refund's platform share back out of the pool. Whatever was already swept is
+// paid in by the owner so every balance stays backed by tokens the contract holds.
+fn debit_treasury(env: &Env, owner: &Address, asset: &Address, amount: i128) {
+    let key = DataKey::TreasuryBalance(asset.clone());
+    let balance: i128 = read_persistent(env, &key).unwrap_or(0);
+    let from_pool = balance.min(amount);
+    write_persistent(env, &key, &(balance - from_pool));
+    if amount > from_pool {
+        token::Client::new(env, asset).transfer(
+            owner,
+            &env.current_contract_address(),
+            &(amount - from_pool),
+        );
+    }
+}
+
+fn read_hold_period(env: &Env) -> u32 {
+    env.storage().instance().get(&DataKey::HoldPeriod).unwrap_or(0)
+}
+
+// Pending credits, oldest unlock first
+fn read_pending(env: &Env, user: &Address, asset: &Address) -> Vec<PendingCredit> {
+    read_persistent(env, &DataKey::Pending(user.clone(), asset.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn write_pending(env: &Env, user: &Address, asset: &Address, pending: &Vec<PendingCredit>) {
+    let key = DataKey::Pending(user.clone(), asset.clone());
+    if pending.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        write_persistent(env, &key, pending);
+    }
+}
+
+// (unlocked, still held) totals as of the current ledger
+fn split_pending(env: &Env, pending: &Vec<PendingCredit>) -> (i128, i128) {
+    let now = env.ledger().sequence();
+    pending.iter().fold((0, 0), |(unlocked, held), credit| {
+        if credit.unlock_ledger <= now {
+            (unlocked + credit.amount, held)
+        } else {
+            (unlocked, held + credit.amount)
+        }
+    })
+}
+
+// Creator earnings go to the pending bucket for the hold period, rounded up to a bucket
+// boundary so the list stays short
+fn credit_earnings(env: &Env, user: &Address, asset: &Address, amount: i128) {
+    let hold = read_hold_period(env);
+    if hold == 0 || amount == 0 {
+        credit_balance(env, user, asset, amount);
+        return;
+    }
+    let unlock_ledger =
+        (env.ledger().sequence() + hold).div_ceil(HOLD_BUCKET_LEDGERS) * HOLD_BUCKET_LEDGERS;
+    let mut pending = read_pending(env, user, asset);
+    let at = pending
+        .iter()
+        .position(|credit| credit.unlock_ledger >= unlock_ledger)
+        .unwrap_or(pending.len() as usize) as u32;
+    match pending.get(at) {
+        Some(mut credit) if credit.unlock_ledger == unlock_ledger => {
+            credit.amount += amount;
+            pending.set(at, credit);
+        }
+        _ => pending.insert(at, PendingCredit { amount, unlock_ledger }),
+    }
+    write_pending(env, user, asset, &pending);
+}
+
+// Move unlocked credits into the withdrawable balance
+fn settle_pending(env: &Env, user: &Address, asset: &Address) {
+    let mut pending = read_pending(env, user, asset);
+    let now = env.ledger().sequence();
+    let mut unlocked = 0;
+    while let Some(credit) = pending.first() {
+        if credit.unlock_ledger > now {
+            break;
+        }
+        unlocked += credit.amount;
+        pending.pop_front();
+    }
+    if unlocked > 0 {
+        credit_balance(env, user, asset, unlocked);
+        write_pending(env, user, asset, &pending);
+    }
+}
+
+// Remove up to `amount` from held credits, newest first; returns what was taken
+fn take_pending(env: &Env, user: &Address, asset: &Address, amount: i128) -> i128 {
+    let mut pending = read_pending(env, user, asset);
+    let mut taken = 0;
+    while taken < amount {
+        let Some(mut credit) = pending.pop_back() else {
+            break;
+        };
+        let part = credit.amount.min(amount - taken);
+        taken += part;
+        credit.amount -= part;
+        if credit.amount > 0 {
+            pending.push_back(credit);
+        }
+    }
+    if taken > 0 {
+        write_pending(env, user, asset, &pending);
+    }
+    taken
+}
+
+// Reverse earnings, taking from what is still held before the withdrawable balance
+fn debit_earnings(env: &Env, user: &Address, asset: &Address, amount: i128) -> Result<(), Error> {
+    settle_pending(env, user, asset);
+    let taken = take_pending(env, user, asset, amount);
+    debit_balance(env, user, asset, amount - taken)
+}
+
+fn read_idempotency_window(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::IdempotencyWindow)
+        .unwrap_or(DAY_IN_LEDGERS)
+}
+
+// Mint through the external NFT contract; any failure there aborts the whole call
+fn mint_token(
+    env: &Env,
+    to: &Address,
+    name: &String,
+    description: &String,
+    image_url: &String,
+    royalty: Option<Royalty>,
+) -> Result<u32, Error> {
+    let nft_contract_id: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::NftContract)
+        .ok_or(Error::NotInitialized)?;
+
+    let nft_client = NFTContractClient::new(env, &nft_contract_id);
+    let token_id = nft_client.mint(to, name, description, image_url, &royalty);
+    record_history(env, to, HistoryKind::Mint, None, None, 0, Some(token_id as u64));
+
+    env.events().publish(
+        (symbol_short!("minted"), to.clone()),
+        token_id,
+    );
+    Ok(token_id)
+}
+
+// Append to the address's history, starting a new page every HISTORY_PAGE_SIZE entries
+fn record_history(
+    env: &Env,
+    address: &Address,
+    kind: HistoryKind,
+    counterparty: Option<Address>,
+    asset: Option<Address>,
+    amount: i128,
+    reference: Option<u64>,
+) {
+    let len_key = DataKey::HistoryLen(address.clone());
+    let len: u32 = read_persistent(env, &len_key).unwrap_or(0);
+    let page_key = DataKey::HistoryPage(address.clone(), len / HISTORY_PAGE_SIZE);
+    let mut page: Vec<HistoryEntry> =
+        read_persistent(env, &page_key).unwrap_or_else(|| Vec::new(env));
+    page.push_back(HistoryEntry {
+        kind,
+        counterparty,
+        asset,
+        amount,
+        reference,
+        timestamp: env.ledger().timestamp(),
+    });
+    write_persistent(env, &page_key, &page);
+    write_persistent(env, &len_key, &(len + 1));
+}
+
+// Contracts deployed before versioning report version 0
+fn read_version(env: &Env) -> u32 {
+    env.storage().instance().get(&DataKey::Version).unwrap_or(0)
+}
+
+// Move data written by `version` to the layout of `version + 1`; returns false while the
+// step needs further calls
+fn migrate_from(env: &Env, version: u32, legacy_users: &Vec<Address>) -> Result<bool, Error> {
+    match version {
+        // Unversioned deployments kept one simulated balance per user in instance storage.
+        // Move the given users' balances to the first allowed asset, with the owner paying
+        // in the tokens that back them.
+        0 => {
+            if legacy_users.is_empty() {
+                return Ok(true);
+            }
+            if legacy_users.len() > MAX_LEGACY_BALANCES {
+                return Err(Error::InvalidBatchSize);
+            }
+            let asset = read_assets(env).first().ok_or(Error::AssetNotAllowed)?;
+            let mut total = 0;
+            for user in legacy_users.iter() {
+                let key = LegacyDataKey::Balance(user.clone());
+                let balance: Option<i128> = env.storage().instance().get(&key);
+                if let Some(balance) = balance {
+                    env.storage().instance().remove(&key);
+                    credit_balance(env, &user, &asset, balance);
+                    total += balance;
+                }
+            }
+            if total > 0 {
+                token::Client::new(env, &asset).transfer(
+                    &read_owner(env)?,
+                    &env.current_contract_address(),
+                    &total,
+                );
+            }
+            Ok(false)
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn require_not_paused(env: &Env, function: &str) -> Result<(), Error> {
+    let allowed: Option<Vec<Symbol>> = env.storage().instance().get(&DataKey::Paused);
+    match allowed {
+        Some(allowed) if !allowed.contains(Symbol::new(env, function)) => {
+            Err(Error::ContractPaused)
+        }
+        _ => Ok(()),
+    }
+}
+
+fn read_allowances(env: &Env, user: &Address) -> Vec<Allowance> {
+    read_persistent(env, &DataKey::Allowances(user.clone())).unwrap_or_else(|| Vec::new(env))
+}
+
+fn position_of_allowance(
+    allowances: &Vec<Allowance>,
+    creator: &Option<Address>,
+    asset: &Address,
+) -> Option<u32> {
+    allowances
+        .iter()
+        .position(|a| a.creator == *creator && a.asset == *asset)
+        .map(|index| index as u32)
+}
+
+// Pick the live allowance covering this spend: the creator's own entry, else the global one.
+// Read-only so callers can validate everything before any balance moves.
+fn find_allowance(
+    env: &Env,
+    user: &Address,
+    creator: &Address,
+    asset: &Address,
+    amount: i128,
+) -> Result<(Vec<Allowance>, u32), Error> {
+    let allowances = read_allowances(env, user);
+    let ledger = env.ledger().sequence();
+    let live = |index: Option<u32>| {
+        index.filter(|&i| allowances.get_unchecked(i).expiration_ledger >= ledger)
+    };
+    let index = live(position_of_allowance(&allowances, &Some(creator.clone()), asset))
+        .or_else(|| live(position_of_allowance(&allowances, &None, asset)))
+        .ok_or(Error::AllowanceMissing)?;
+    if allowances.get_unchecked(index).amount < amount {
+        return Err(Error::AllowanceExhausted);
+    }
+    Ok((allowances, index))
+}
+
+fn spend_allowance(
+    env: &Env,
+    user: &Address,
+    mut allowances: Vec<Allowance>,
+    index: u32,
+    amount: i128,
+) {
+    let mut allowance = allowances.get_unchecked(index);
+    allowance.amount -= amount;
+    allowances.set(index, allowance);
+    write_persistent(env, &DataKey::Allowances(user.clone()), &allowances);
+}
+
+fn read_assets(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Assets)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn require_allowed_asset(env: &Env, asset: &Address) -> Result<(), Error> {
+    if !read_assets(env).contains(asset) {
+        return Err(Error::AssetNotAllowed);
+    }
+    Ok(())
+}
+
+fn read_royalty_bps(env: &Env, creator: &Address) -> u32 {
+    if let Some(bps) = read_persistent(env, &DataKey::CreatorRoyaltyBps(creator.clone())) {
+        return bps;
+    }
+    env.storage()
+        .instance()
+        .get(&DataKey::RoyaltyBps)
+        .unwrap_or(DEFAULT_ROYALTY_BPS)
+}
+
+fn read_resale_royalty_bps(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ResaleRoyaltyBps)
+        .unwrap_or(DEFAULT_RESALE_ROYALTY_BPS)
+}
+
+// Persistent entries are bumped whenever they are read or written
+fn read_persistent<V: TryFromVal<Env, Val>>(env: &Env, key: &DataKey) -> Option<V> {
+    let value = env.storage().persistent().get(key);
+    if value.is_some() {
+        extend_persistent(env, key);
+    }
+    value
+}
+
+fn write_persistent<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+    env.storage().persistent().set(key, value);
+    extend_persistent(env, key);
+}
+
+fn extend_instance(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
+
+fn extend_persistent(env: &Env, key: &DataKey) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+fn read_balance(env: &Env, user: &Address, asset: &Address) -> i128 {
+    read_persistent(env, &DataKey::Balance(user.clone(), asset.clone())).unwrap_or(0)
+}
+
+fn write_balance(env: &Env, user: &Address, asset: &Address, amount: i128) {
+    write_persistent(env, &DataKey::Balance(user.clone(), asset.clone()), &amount);
+}
+
+fn credit_balance(env: &Env, user: &Address, asset: &Address, amount: i128) {
+    let balance = read_balance(env, user, asset);
+    write_balance(env, user, asset, balance + amount);
+}
+
+fn debit_balance(env: &Env, user: &Address, asset: &Address, amount: i128) -> Result<(), Error> {
+    settle_pending(env, user, asset);
+    let balance = read_balance(env, user, asset);
+    if balance < amount {
+        return Err(Error::InsufficientBalance);
+    }
+    write_balance(env, user, asset, balance - amount);
+    Ok(())
+}
+
+#[cfg(test)]
+mod test;
@@ -1,49 +1,238 @@
-use soroban_sdk::String as SorobanString;
 use core::clone::Clone;
 use core::convert::{Into, From, TryInto};
 use core::iter::{Iterator, ExactSizeIterator};
 use core::result::Result;
 
-pub const WASM: &[u8] = soroban_sdk::contractfile!(
-  file =
-  "\\code\\consensus\\contracts\\target\\wasm32v1-none\\release\\onlyfrens_nft.wasm",
-  sha256 = "29efa3d3eb3999d8d98e1651e03fa1442b85ec30f2bfbfcf357108ea30d23de3"
-);
 #[soroban_sdk::contractargs(name = "Args")]
 #[soroban_sdk::contractclient(name = "Client")]
 pub trait Contract {
-  fn initialize(env: soroban_sdk::Env, admin: soroban_sdk::Address);
-  fn mint(
-      env: soroban_sdk::Env,
-      to: soroban_sdk::Address,
-      name: SorobanString,
-      description: SorobanString,
-      image_url: SorobanString,
-  ) -> u32;
-  fn transfer(
-      env: soroban_sdk::Env,
-      from: soroban_sdk::Address,
-      to: soroban_sdk::Address,
-      token_id: u32,
-  );
-  fn total_supply(env: soroban_sdk::Env) -> u32;
-  fn owner_of(env: soroban_sdk::Env, token_id: u32) -> soroban_sdk::Address;
-  fn token_metadata(env: soroban_sdk::Env, token_id: u32) -> NFTMetadata;
-  fn token_uri(env: soroban_sdk::Env, token_id: u32) -> SorobanString;
-  fn get_admin(env: soroban_sdk::Env) -> soroban_sdk::Address;
+    fn burn(
+        env: soroban_sdk::Env,
+        from: soroban_sdk::Address,
+        token_id: u32,
+    ) -> Result<(), soroban_sdk::Error>;
+    fn mint(
+        env: soroban_sdk::Env,
+        to: soroban_sdk::Address,
+        name: soroban_sdk::String,
+        description: soroban_sdk::String,
+        image_url: soroban_sdk::String,
+        royalty: Option<Royalty>,
+    ) -> Result<u32, soroban_sdk::Error>;
+    fn name(env: soroban_sdk::Env) -> Result<soroban_sdk::String, soroban_sdk::Error>;
+    fn symbol(env: soroban_sdk::Env) -> Result<soroban_sdk::String, soroban_sdk::Error>;
+    fn approve(
+        env: soroban_sdk::Env,
+        approver: soroban_sdk::Address,
+        approved: soroban_sdk::Address,
+        token_id: u32,
+        live_until_ledger: u32,
+    ) -> Result<(), soroban_sdk::Error>;
+    fn balance(
+        env: soroban_sdk::Env,
+        owner: soroban_sdk::Address,
+    ) -> Result<u32, soroban_sdk::Error>;
+    fn migrate(
+        env: soroban_sdk::Env,
+        max_tokens: u32,
+    ) -> Result<u32, soroban_sdk::Error>;
+    fn upgrade(
+        env: soroban_sdk::Env,
+        new_wasm_hash: soroban_sdk::BytesN<32>,
+    ) -> Result<(), soroban_sdk::Error>;
+    fn version(env: soroban_sdk::Env) -> Result<u32, soroban_sdk::Error>;
+    fn owner_of(
+        env: soroban_sdk::Env,
+        token_id: u32,
+    ) -> Result<soroban_sdk::Address, soroban_sdk::Error>;
+    fn transfer(
+        env: soroban_sdk::Env,
+        from: soroban_sdk::Address,
+        to: soroban_sdk::Address,
+        token_id: u32,
+    ) -> Result<(), soroban_sdk::Error>;
+    fn burn_from(
+        env: soroban_sdk::Env,
+        spender: soroban_sdk::Address,
+        from: soroban_sdk::Address,
+        token_id: u32,
+    ) -> Result<(), soroban_sdk::Error>;
+    fn get_admin(
+        env: soroban_sdk::Env,
+    ) -> Result<soroban_sdk::Address, soroban_sdk::Error>;
+    fn token_uri(
+        env: soroban_sdk::Env,
+        token_id: u32,
+    ) -> Result<soroban_sdk::String, soroban_sdk::Error>;
+    fn admin_burn(
+        env: soroban_sdk::Env,
+        token_id: u32,
+    ) -> Result<(), soroban_sdk::Error>;
+    fn edition_of(
+        env: soroban_sdk::Env,
+        token_id: u32,
+    ) -> Result<Option<Edition>, soroban_sdk::Error>;
+    fn initialize(
+        env: soroban_sdk::Env,
+        admin: soroban_sdk::Address,
+        name: soroban_sdk::String,
+        symbol: soroban_sdk::String,
+    ) -> Result<(), soroban_sdk::Error>;
+    fn get_approved(
+        env: soroban_sdk::Env,
+        token_id: u32,
+    ) -> Result<Option<soroban_sdk::Address>, soroban_sdk::Error>;
+    fn mint_edition(
+        env: soroban_sdk::Env,
+        to: soroban_sdk::Address,
+        collection_id: u32,
+    ) -> Result<u32, soroban_sdk::Error>;
+    fn royalty_info(
+        env: soroban_sdk::Env,
+        token_id: u32,
+        sale_price: i128,
+    ) -> Result<(soroban_sdk::Address, i128), soroban_sdk::Error>;
+    fn total_burned(env: soroban_sdk::Env) -> Result<u32, soroban_sdk::Error>;
+    fn total_minted(env: soroban_sdk::Env) -> Result<u32, soroban_sdk::Error>;
+    fn total_supply(env: soroban_sdk::Env) -> Result<u32, soroban_sdk::Error>;
+    fn propose_owner(
+        env: soroban_sdk::Env,
+        new_admin: soroban_sdk::Address,
+    ) -> Result<(), soroban_sdk::Error>;
+    fn transfer_from(
+        env: soroban_sdk::Env,
+        spender: soroban_sdk::Address,
+        from: soroban_sdk::Address,
+        to: soroban_sdk::Address,
+        token_id: u32,
+    ) -> Result<(), soroban_sdk::Error>;
+    fn get_collection(
+        env: soroban_sdk::Env,
+        collection_id: u32,
+    ) -> Result<Collection, soroban_sdk::Error>;
+    fn token_metadata(
+        env: soroban_sdk::Env,
+        token_id: u32,
+    ) -> Result<NFTMetadata, soroban_sdk::Error>;
+    fn approve_for_all(
+        env: soroban_sdk::Env,
+        owner: soroban_sdk::Address,
+        operator: soroban_sdk::Address,
+        live_until_ledger: u32,
+    ) -> Result<(), soroban_sdk::Error>;
+    fn tokens_of_owner(
+        env: soroban_sdk::Env,
+        owner: soroban_sdk::Address,
+        start: u32,
+        limit: u32,
+    ) -> Result<soroban_sdk::Vec<u32>, soroban_sdk::Error>;
+    fn accept_ownership(env: soroban_sdk::Env) -> Result<(), soroban_sdk::Error>;
+    fn remaining_supply(
+        env: soroban_sdk::Env,
+        collection_id: u32,
+    ) -> Result<u32, soroban_sdk::Error>;
+    fn create_collection(
+        env: soroban_sdk::Env,
+        creator: soroban_sdk::Address,
+        metadata: NFTMetadata,
+        max_supply: u32,
+        royalty: Option<Royalty>,
+    ) -> Result<u32, soroban_sdk::Error>;
+    fn get_pending_owner(
+        env: soroban_sdk::Env,
+    ) -> Result<Option<soroban_sdk::Address>, soroban_sdk::Error>;
+    fn is_approved_for_all(
+        env: soroban_sdk::Env,
+        owner: soroban_sdk::Address,
+        operator: soroban_sdk::Address,
+    ) -> Result<bool, soroban_sdk::Error>;
+    fn set_collection_info(
+        env: soroban_sdk::Env,
+        name: soroban_sdk::String,
+        symbol: soroban_sdk::String,
+    ) -> Result<(), soroban_sdk::Error>;
+    fn set_default_royalty(
+        env: soroban_sdk::Env,
+        royalty: Option<Royalty>,
+    ) -> Result<(), soroban_sdk::Error>;
+    fn cancel_ownership_transfer(
+        env: soroban_sdk::Env,
+    ) -> Result<(), soroban_sdk::Error>;
+}
+#[soroban_sdk::contracttype(export = false)]
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Edition {
+    pub collection_id: u32,
+    pub max_supply: u32,
+    pub number: u32,
+}
+#[soroban_sdk::contracttype(export = false)]
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Royalty {
+    pub bps: u32,
+    pub receiver: soroban_sdk::Address,
+}
+#[soroban_sdk::contracttype(export = false)]
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Collection {
+    pub creator: soroban_sdk::Address,
+    pub max_supply: u32,
+    pub metadata: NFTMetadata,
+    pub minted: u32,
 }
 #[soroban_sdk::contracttype(export = false)]
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
 pub struct NFTMetadata {
-  pub description: SorobanString,
-  pub image_url: SorobanString,
-  pub name: SorobanString,
+    pub description: soroban_sdk::String,
+    pub image_url: soroban_sdk::String,
+    pub name: soroban_sdk::String,
+}
+#[soroban_sdk::contracttype(export = false)]
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct ApprovalData {
+    pub approved: soroban_sdk::Address,
+    pub live_until_ledger: u32,
 }
 #[soroban_sdk::contracttype(export = false)]
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
 pub enum DataKey {
-  Admin,
-  TokenOwner(u32),
-  TokenMetadata(u32),
-  TotalSupply,
-}
\ No newline at end of file
+    Admin,
+    PendingAdmin,
+    Version,
+    MigrationCursor,
+    TokenOwner(u32),
+    TokenMetadata(u32),
+    Name,
+    TokenSymbol,
+    TotalSupply,
+    TotalMinted,
+    TotalBurned,
+    Approval(u32),
+    OperatorApproval(soroban_sdk::Address, soroban_sdk::Address),
+    OwnerBalance(soroban_sdk::Address),
+    OwnedToken(soroban_sdk::Address, u32),
+    OwnedTokenIndex(u32),
+    DefaultRoyalty,
+    TokenRoyalty(u32),
+    CollectionCount,
+    Collection(u32),
+    CollectionRoyalty(u32),
+    TokenEdition(u32),
+}
+#[soroban_sdk::contracterror(export = false)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Error {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    TokenNotFound = 3,
+    NotTokenOwner = 4,
+    NoPendingOwner = 5,
+    NotApproved = 6,
+    InvalidLiveUntilLedger = 7,
+    InvalidRoyalty = 8,
+    RoyaltyNotSet = 9,
+    CollectionNotFound = 10,
+    InvalidMaxSupply = 11,
+    CollectionSoldOut = 12,
+    InvalidSalePrice = 13,
+}
@@ -0,0 +1,972 @@
+#![cfg(test)]
+use super::*;
+use core::cell::Cell;
+use soroban_sdk::{
+    testutils::{
+        storage::{Instance as _, Persistent as _},
+        Address as _, Ledger,
+    },
+    token::{StellarAssetClient, TokenClient},
+    BytesN, Env,
+};
+
+mod mock_nft {
+    use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, String};
+
+    // Stand-in for the NFT contract: sequential ids, refuses to mint "fail"
+    #[contract]
+    pub struct MockNft;
+
+    #[contractimpl]
+    impl MockNft {
+        pub fn mint(
+            env: Env,
+            _to: Address,
+            name: String,
+            _description: String,
+            _image_url: String,
+            royalty: Option<crate::nft_client::Royalty>,
+        ) -> u32 {
+            if name == String::from_str(&env, "fail") {
+                panic!("mint failed");
+            }
+            let key = symbol_short!("next");
+            let id: u32 = env.storage().instance().get(&key).unwrap_or(0) + 1;
+            env.storage().instance().set(&key, &id);
+            env.storage().instance().set(&id, &royalty);
+            id
+        }
+
+        pub fn royalty_of(env: Env, token_id: u32) -> Option<crate::nft_client::Royalty> {
+            env.storage().instance().get(&token_id).unwrap()
+        }
+    }
+}
+
+struct Setup<'a> {
+    env: Env,
+    owner: Address,
+    nft: Address,
+    xlm: Address,
+    token: TokenClient<'a>,
+    token_admin: StellarAssetClient<'a>,
+    client: PlatformContractClient<'a>,
+    nonces: Cell<u32>,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let nft_contract_id = env.register(mock_nft::MockNft, ());
+    let sac = env.register_stellar_asset_contract_v2(owner.clone());
+    let token = TokenClient::new(&env, &sac.address());
+    let token_admin = StellarAssetClient::new(&env, &sac.address());
+
+    let contract_id = env.register(PlatformContract, ());
+    let client = PlatformContractClient::new(&env, &contract_id);
+    client.initialize(&owner, &nft_contract_id, &sac.address());
+
+    Setup {
+        env,
+        owner,
+        nft: nft_contract_id,
+        xlm: sac.address(),
+        token,
+        token_admin,
+        client,
+        nonces: Cell::new(0),
+    }
+}
+
+// Fresh idempotency key for each admin_spend
+fn nonce(s: &Setup) -> BytesN<32> {
+    let n = s.nonces.get() + 1;
+    s.nonces.set(n);
+    let mut bytes = [0u8; 32];
+    bytes[..4].copy_from_slice(&n.to_be_bytes());
+    BytesN::from_array(&s.env, &bytes)
+}
+
+// Let the platform charge `user` up to `amount` of `asset` for any creator
+fn allow_spending(s: &Setup, user: &Address, asset: &Address, amount: i128) {
+    let expiration_ledger = s.env.ledger().sequence() + 1000;
+    s.client.approve_spending(user, &None, asset, &amount, &expiration_ledger);
+}
+
+#[test]
+fn test_deposit_and_withdraw_move_tokens() {
+    let s = setup();
+    let user = Address::generate(&s.env);
+    s.token_admin.mint(&user, &(100 * MIN_AMOUNT));
+
+    s.client.deposit(&user, &s.xlm, &(40 * MIN_AMOUNT));
+    assert_eq!(s.client.get_balance(&user, &s.xlm), 40 * MIN_AMOUNT);
+    assert_eq!(s.token.balance(&user), 60 * MIN_AMOUNT);
+    assert_eq!(s.token.balance(&s.client.address), 40 * MIN_AMOUNT);
+
+    s.client.withdraw(&user, &s.xlm, &(15 * MIN_AMOUNT));
+    assert_eq!(s.client.get_balance(&user, &s.xlm), 25 * MIN_AMOUNT);
+    assert_eq!(s.token.balance(&user), 75 * MIN_AMOUNT);
+    assert_eq!(s.token.balance(&s.client.address), 25 * MIN_AMOUNT);
+}
+
+#[test]
+fn test_spend_keeps_contract_holdings_backed() {
+    let s = setup();
+    let fan = Address::generate(&s.env);
+    let creator = Address::generate(&s.env);
+    s.token_admin.mint(&fan, &(10 * MIN_AMOUNT));
+    s.client.deposit(&fan, &s.xlm, &(10 * MIN_AMOUNT));
+    allow_spending(&s, &fan, &s.xlm, 10 * MIN_AMOUNT);
+
+    s.client.admin_spend(
+        &fan,
+        &creator,
+        &s.xlm,
+        &(10 * MIN_AMOUNT),
+        &String::from_str(&s.env, "tip"),
+        &None,
+        &nonce(&s),
+    );
+    assert_eq!(s.client.get_balance(&fan, &s.xlm), 0);
+    assert_eq!(s.client.get_balance(&creator, &s.xlm), 9 * MIN_AMOUNT);
+    assert_eq!(s.client.get_treasury_balance(&s.xlm), MIN_AMOUNT);
+
+    s.client.withdraw(&creator, &s.xlm, &(9 * MIN_AMOUNT));
+    assert_eq!(s.client.sweep_treasury(&s.xlm), MIN_AMOUNT);
+    assert_eq!(s.token.balance(&creator), 9 * MIN_AMOUNT);
+    assert_eq!(s.token.balance(&s.owner), MIN_AMOUNT);
+    assert_eq!(s.token.balance(&s.client.address), 0);
+}
+
+#[test]
+#[should_panic]
+fn test_deposit_without_funds_fails() {
+    let s = setup();
+    let user = Address::generate(&s.env);
+    s.client.deposit(&user, &s.xlm, &MIN_AMOUNT);
+}
+
+#[test]
+fn test_errors_are_typed() {
+    let s = setup();
+    let user = Address::generate(&s.env);
+    let creator = Address::generate(&s.env);
+    s.token_admin.mint(&user, &(5 * MIN_AMOUNT));
+    s.client.deposit(&user, &s.xlm, &(2 * MIN_AMOUNT));
+    allow_spending(&s, &user, &s.xlm, 10 * MIN_AMOUNT);
+
+    assert_eq!(
+        s.client.try_initialize(&s.owner, &s.owner, &s.xlm),
+        Err(Ok(Error::AlreadyInitialized))
+    );
+    assert_eq!(
+        s.client.try_deposit(&user, &s.xlm, &(MIN_AMOUNT - 1)),
+        Err(Ok(Error::AmountBelowMinimum))
+    );
+    assert_eq!(
+        s.client.try_withdraw(&user, &s.xlm, &(3 * MIN_AMOUNT)),
+        Err(Ok(Error::InsufficientBalance))
+    );
+    assert_eq!(
+        s.client.try_admin_spend(
+            &user,
+            &creator,
+            &s.xlm,
+            &(3 * MIN_AMOUNT),
+            &String::from_str(&s.env, "tip"),
+            &None,
+            &nonce(&s),
+        ),
+        Err(Ok(Error::InsufficientBalance))
+    );
+}
+
+#[test]
+fn test_balances_are_persistent_with_ttl() {
+    let s = setup();
+    let user = Address::generate(&s.env);
+    s.token_admin.mint(&user, &(5 * MIN_AMOUNT));
+
+    assert_eq!(
+        s.client.try_extend_balance_ttl(&user, &s.xlm),
+        Err(Ok(Error::BalanceNotFound))
+    );
+    s.client.deposit(&user, &s.xlm, &(5 * MIN_AMOUNT));
+
+    let key = DataKey::Balance(user.clone(), s.xlm.clone());
+    let ttl = s.env.as_contract(&s.client.address, || {
+        assert!(!s.env.storage().instance().has(&key));
+        s.env.storage().persistent().get_ttl(&key)
+    });
+    assert_eq!(ttl, PERSISTENT_BUMP_AMOUNT);
+
+    s.env.ledger().with_mut(|li| li.sequence_number += 2 * DAY_IN_LEDGERS);
+    s.client.extend_balance_ttl(&user, &s.xlm);
+    let (ttl, instance_ttl) = s.env.as_contract(&s.client.address, || {
+        let storage = s.env.storage();
+        (storage.persistent().get_ttl(&key), storage.instance().get_ttl())
+    });
+    assert_eq!(ttl, PERSISTENT_BUMP_AMOUNT);
+    // The config in instance storage must outlive the jump as well
+    assert_eq!(instance_ttl, INSTANCE_BUMP_AMOUNT);
+    assert_eq!(s.client.get_balance(&user, &s.xlm), 5 * MIN_AMOUNT);
+}
+
+#[test]
+fn test_royalty_rates() {
+    let s = setup();
+    let fan = Address::generate(&s.env);
+    let creator = Address::generate(&s.env);
+    let partner = Address::generate(&s.env);
+    s.token_admin.mint(&fan, &(20 * MIN_AMOUNT));
+    s.client.deposit(&fan, &s.xlm, &(20 * MIN_AMOUNT));
+    allow_spending(&s, &fan, &s.xlm, 20 * MIN_AMOUNT);
+
+    assert_eq!(s.client.get_royalty_bps(&creator), DEFAULT_ROYALTY_BPS);
+    s.client.set_royalty_bps(&2000);
+    s.client.set_creator_royalty_bps(&partner, &Some(500));
+    assert_eq!(s.client.get_royalty_bps(&creator), 2000);
+    assert_eq!(s.client.get_royalty_bps(&partner), 500);
+    assert_eq!(
+        s.client.try_set_creator_royalty_bps(&partner, &Some(MAX_ROYALTY_BPS + 1)),
+        Err(Ok(Error::InvalidRoyaltyBps))
+    );
+
+    let tx_type = String::from_str(&s.env, "tip");
+    let amount = 10 * MIN_AMOUNT;
+    s.client.admin_spend(&fan, &creator, &s.xlm, &amount, &tx_type, &None, &nonce(&s));
+    s.client.admin_spend(&fan, &partner, &s.xlm, &amount, &tx_type, &None, &nonce(&s));
+    assert_eq!(s.client.get_balance(&creator, &s.xlm), 8 * MIN_AMOUNT);
+    assert_eq!(s.client.get_balance(&partner, &s.xlm), 95 * MIN_AMOUNT / 10);
+    assert_eq!(s.client.get_treasury_balance(&s.xlm), 25 * MIN_AMOUNT / 10);
+
+    s.client.set_creator_royalty_bps(&partner, &None);
+    assert_eq!(s.client.get_royalty_bps(&partner), 2000);
+}
+
+#[test]
+fn test_balances_are_tracked_per_asset() {
+    let s = setup();
+    let fan = Address::generate(&s.env);
+    let creator = Address::generate(&s.env);
+    let usdc_sac = s.env.register_stellar_asset_contract_v2(s.owner.clone());
+    let usdc = usdc_sac.address();
+    StellarAssetClient::new(&s.env, &usdc).mint(&fan, &(10 * MIN_AMOUNT));
+    s.token_admin.mint(&fan, &(10 * MIN_AMOUNT));
+
+    assert_eq!(
+        s.client.try_deposit(&fan, &usdc, &(10 * MIN_AMOUNT)),
+        Err(Ok(Error::AssetNotAllowed))
+    );
+    s.client.add_asset(&usdc);
+    assert_eq!(s.client.try_add_asset(&usdc), Err(Ok(Error::AssetAlreadyAllowed)));
+    assert_eq!(s.client.get_assets(), Vec::from_array(&s.env, [s.xlm.clone(), usdc.clone()]));
+
+    s.client.deposit(&fan, &usdc, &(10 * MIN_AMOUNT));
+    s.client.deposit(&fan, &s.xlm, &(4 * MIN_AMOUNT));
+    allow_spending(&s, &fan, &usdc, 10 * MIN_AMOUNT);
+    s.client.admin_spend(
+        &fan,
+        &creator,
+        &usdc,
+        &(10 * MIN_AMOUNT),
+        &String::from_str(&s.env, "sub"),
+        &None,
+        &nonce(&s),
+    );
+    assert_eq!(s.client.get_balance(&fan, &usdc), 0);
+    assert_eq!(s.client.get_balance(&fan, &s.xlm), 4 * MIN_AMOUNT);
+    assert_eq!(s.client.get_balance(&creator, &usdc), 9 * MIN_AMOUNT);
+    assert_eq!(s.client.get_balance(&creator, &s.xlm), 0);
+
+    // Delisted assets can no longer be deposited but can still be withdrawn
+    s.client.remove_asset(&usdc);
+    assert!(!s.client.is_asset_allowed(&usdc));
+    s.client.withdraw(&creator, &usdc, &(9 * MIN_AMOUNT));
+    assert_eq!(TokenClient::new(&s.env, &usdc).balance(&creator), 9 * MIN_AMOUNT);
+}
+
+#[test]
+fn test_tip_requires_fan_auth() {
+    let s = setup();
+    let fan = Address::generate(&s.env);
+    let creator = Address::generate(&s.env);
+    s.token_admin.mint(&fan, &(10 * MIN_AMOUNT));
+    s.client.deposit(&fan, &s.xlm, &(10 * MIN_AMOUNT));
+
+    let memo = String::from_str(&s.env, "great stream!");
+    s.client.tip(&fan, &creator, &s.xlm, &(10 * MIN_AMOUNT), &memo);
+    assert_eq!(
+        s.env.auths()[0].0,
+        fan,
+        "tip must be authorized by the fan, not the owner"
+    );
+    assert_eq!(s.client.get_balance(&fan, &s.xlm), 0);
+    assert_eq!(s.client.get_balance(&creator, &s.xlm), 9 * MIN_AMOUNT);
+    assert_eq!(s.client.get_treasury_balance(&s.xlm), MIN_AMOUNT);
+}
+
+#[test]
+fn test_purchase_nft_charges_and_mints_atomically() {
+    let s = setup();
+    let buyer = Address::generate(&s.env);
+    let creator = Address::generate(&s.env);
+    s.token_admin.mint(&buyer, &(10 * MIN_AMOUNT));
+    s.client.deposit(&buyer, &s.xlm, &(10 * MIN_AMOUNT));
+    allow_spending(&s, &buyer, &s.xlm, 10 * MIN_AMOUNT);
+
+    let text = |t: &str| String::from_str(&s.env, t);
+    let token_id = s.client.purchase_nft(
+        &buyer,
+        &creator,
+        &s.xlm,
+        &(5 * MIN_AMOUNT),
+        &text("Backstage pass"),
+        &text("Premium video access"),
+        &text("https://example.com/pass.png"),
+    );
+    assert_eq!(token_id, 1);
+    assert_eq!(s.client.get_balance(&buyer, &s.xlm), 5 * MIN_AMOUNT);
+    assert_eq!(s.client.get_balance(&creator, &s.xlm), 45 * MIN_AMOUNT / 10);
+    assert_eq!(s.client.get_treasury_balance(&s.xlm), 5 * MIN_AMOUNT / 10);
+
+    // A failed mint leaves the buyer uncharged
+    let result = s.client.try_purchase_nft(
+        &buyer,
+        &creator,
+        &s.xlm,
+        &(5 * MIN_AMOUNT),
+        &text("fail"),
+        &text("Premium video access"),
+        &text("https://example.com/pass.png"),
+    );
+    assert!(result.is_err());
+    assert_eq!(s.client.get_balance(&buyer, &s.xlm), 5 * MIN_AMOUNT);
+    assert_eq!(s.client.get_balance(&creator, &s.xlm), 45 * MIN_AMOUNT / 10);
+
+    // The creator is paid on resales of the pass
+    let nft = mock_nft::MockNftClient::new(&s.env, &s.nft);
+    let royalty = Royalty {
+        receiver: creator.clone(),
+        bps: 500,
+    };
+    assert_eq!(nft.royalty_of(&token_id), Some(royalty));
+    assert_eq!(
+        s.client.try_set_resale_royalty_bps(&5001),
+        Err(Ok(Error::InvalidRoyaltyBps))
+    );
+    s.client.set_resale_royalty_bps(&0);
+    assert_eq!(s.client.get_resale_royalty_bps(), 0);
+    let token_id = s.client.purchase_nft(
+        &buyer,
+        &creator,
+        &s.xlm,
+        &MIN_AMOUNT,
+        &text("Backstage pass"),
+        &text("Premium video access"),
+        &text("https://example.com/pass.png"),
+    );
+    assert_eq!(nft.royalty_of(&token_id), None);
+}
+
+#[test]
+fn test_subscriptions_record_and_extend_expiry() {
+    let s = setup();
+    let fan = Address::generate(&s.env);
+    let creator = Address::generate(&s.env);
+    s.token_admin.mint(&fan, &(20 * MIN_AMOUNT));
+    s.client.deposit(&fan, &s.xlm, &(20 * MIN_AMOUNT));
+    s.env.ledger().with_mut(|li| li.timestamp = 1_000);
+
+    assert_eq!(
+        s.client.try_subscribe(&fan, &creator, &1),
+        Err(Ok(Error::NoSubscriptionPlan))
+    );
+    s.client.set_subscription_plan(&creator, &s.xlm, &(5 * MIN_AMOUNT));
+    assert_eq!(s.client.try_subscribe(&fan, &creator, &0), Err(Ok(Error::InvalidPeriods)));
+
+    let expires_at = s.client.subscribe(&fan, &creator, &1);
+    assert_eq!(expires_at, 1_000 + SUBSCRIPTION_PERIOD_SECS);
+    assert!(s.client.is_subscribed(&fan, &creator));
+    assert_eq!(s.client.get_balance(&fan, &s.xlm), 15 * MIN_AMOUNT);
+    assert_eq!(s.client.get_balance(&creator, &s.xlm), 45 * MIN_AMOUNT / 10);
+
+    // Renewing before expiry stacks on top of the remaining time
+    s.env.ledger().with_mut(|li| li.timestamp = 2_000);
+    let renewed = s.client.subscribe(&fan, &creator, &2);
+    assert_eq!(renewed, expires_at + 2 * SUBSCRIPTION_PERIOD_SECS);
+    assert_eq!(
+        s.client.get_subscription(&fan, &creator),
+        Some(Subscription { expires_at: renewed })
+    );
+    assert_eq!(s.client.get_balance(&fan, &s.xlm), 5 * MIN_AMOUNT);
+
+    s.env.ledger().with_mut(|li| li.timestamp = renewed);
+    assert!(!s.client.is_subscribed(&fan, &creator));
+
+    // Price times periods is checked rather than left to wrap
+    s.client.set_subscription_plan(&creator, &s.xlm, &(i128::MAX / 2));
+    assert_eq!(s.client.try_subscribe(&fan, &creator, &3), Err(Ok(Error::AmountOverflow)));
+}
+
+#[test]
+fn test_two_step_ownership_transfer() {
+    let s = setup();
+    let new_owner = Address::generate(&s.env);
+
+    assert_eq!(s.client.try_accept_ownership(), Err(Ok(Error::NoPendingOwner)));
+    s.client.propose_owner(&new_owner);
+    assert_eq!(s.client.get_pending_owner(), Some(new_owner.clone()));
+    assert_eq!(s.client.get_owner(), s.owner);
+
+    s.client.cancel_ownership_transfer();
+    assert_eq!(s.client.get_pending_owner(), None);
+    assert_eq!(s.client.try_accept_ownership(), Err(Ok(Error::NoPendingOwner)));
+
+    s.client.propose_owner(&new_owner);
+    s.client.accept_ownership();
+    assert_eq!(s.env.auths()[0].0, new_owner);
+    assert_eq!(s.client.get_owner(), new_owner);
+    assert_eq!(s.client.get_pending_owner(), None);
+}
+
+#[test]
+fn test_pause_blocks_money_paths() {
+    let s = setup();
+    let fan = Address::generate(&s.env);
+    let creator = Address::generate(&s.env);
+    s.token_admin.mint(&fan, &(10 * MIN_AMOUNT));
+    s.client.deposit(&fan, &s.xlm, &(5 * MIN_AMOUNT));
+    assert!(!s.client.is_paused());
+
+    let allowed = Vec::from_array(&s.env, [Symbol::new(&s.env, "withdraw")]);
+    s.client.pause(&allowed);
+    assert!(s.client.is_paused());
+    assert_eq!(s.client.get_pause_allowlist(), allowed);
+    assert_eq!(
+        s.client.try_deposit(&fan, &s.xlm, &MIN_AMOUNT),
+        Err(Ok(Error::ContractPaused))
+    );
+    assert_eq!(
+        s.client.try_admin_spend(
+            &fan,
+            &creator,
+            &s.xlm,
+            &MIN_AMOUNT,
+            &String::from_str(&s.env, "tip"),
+            &None,
+            &nonce(&s),
+        ),
+        Err(Ok(Error::ContractPaused))
+    );
+    s.client.withdraw(&fan, &s.xlm, &MIN_AMOUNT);
+
+    s.client.pause(&Vec::new(&s.env));
+    assert_eq!(
+        s.client.try_withdraw(&fan, &s.xlm, &MIN_AMOUNT),
+        Err(Ok(Error::ContractPaused))
+    );
+
+    s.client.unpause();
+    s.client.deposit(&fan, &s.xlm, &MIN_AMOUNT);
+    assert_eq!(s.client.get_balance(&fan, &s.xlm), 5 * MIN_AMOUNT);
+}
+
+#[test]
+fn test_migrate_stamps_storage_version() {
+    let s = setup();
+    let none = Vec::new(&s.env);
+    assert_eq!(s.client.version(), STORAGE_VERSION);
+    assert_eq!(s.client.migrate(&none), STORAGE_VERSION);
+
+    // Deployments from before versioning have no stored version
+    s.env.as_contract(&s.client.address, || {
+        s.env.storage().instance().remove(&DataKey::Version);
+    });
+    assert_eq!(s.client.version(), 0);
+    assert_eq!(s.client.migrate(&none), STORAGE_VERSION);
+    assert_eq!(s.client.version(), STORAGE_VERSION);
+}
+
+#[test]
+fn test_migrate_moves_legacy_balances_in_batches() {
+    let s = setup();
+    let fan = Address::generate(&s.env);
+    let creator = Address::generate(&s.env);
+    s.token_admin.mint(&s.owner, &(8 * MIN_AMOUNT));
+
+    // Unversioned deployments kept simulated balances in instance storage
+    s.env.as_contract(&s.client.address, || {
+        let storage = s.env.storage().instance();
+        storage.remove(&DataKey::Version);
+        storage.set(&LegacyDataKey::Balance(fan.clone()), &(5 * MIN_AMOUNT));
+        storage.set(&LegacyDataKey::Balance(creator.clone()), &(3 * MIN_AMOUNT));
+    });
+
+    // Each batch moves its users' balances and the owner pays in the tokens behind them
+    assert_eq!(s.client.migrate(&Vec::from_array(&s.env, [fan.clone()])), 0);
+    assert_eq!(s.client.get_balance(&fan, &s.xlm), 5 * MIN_AMOUNT);
+    assert_eq!(s.token.balance(&s.client.address), 5 * MIN_AMOUNT);
+    let batch = Vec::from_array(&s.env, [creator.clone(), fan.clone()]);
+    assert_eq!(s.client.migrate(&batch), 0);
+    assert_eq!(s.client.get_balance(&creator, &s.xlm), 3 * MIN_AMOUNT);
+    assert_eq!(s.client.get_balance(&fan, &s.xlm), 5 * MIN_AMOUNT);
+    assert_eq!(s.token.balance(&s.owner), 0);
+
+    let mut too_many = Vec::new(&s.env);
+    for _ in 0..=MAX_LEGACY_BALANCES {
+        too_many.push_back(fan.clone());
+    }
+    assert_eq!(s.client.try_migrate(&too_many), Err(Ok(Error::InvalidBatchSize)));
+
+    // An empty batch finishes the step
+    assert_eq!(s.client.migrate(&Vec::new(&s.env)), STORAGE_VERSION);
+    s.client.withdraw(&fan, &s.xlm, &(5 * MIN_AMOUNT));
+    assert_eq!(s.token.balance(&fan), 5 * MIN_AMOUNT);
+}
+
+#[test]
+fn test_allowances_bound_admin_spend() {
+    let s = setup();
+    let fan = Address::generate(&s.env);
+    let creator = Address::generate(&s.env);
+    let other = Address::generate(&s.env);
+    s.token_admin.mint(&fan, &(20 * MIN_AMOUNT));
+    s.client.deposit(&fan, &s.xlm, &(20 * MIN_AMOUNT));
+    let tx_type = String::from_str(&s.env, "tip");
+    let spend = |to: &Address, amount: i128| {
+        s.client.try_admin_spend(&fan, to, &s.xlm, &amount, &tx_type, &None, &nonce(&s))
+    };
+
+    assert_eq!(spend(&creator, MIN_AMOUNT), Err(Ok(Error::AllowanceMissing)));
+
+    let expiration_ledger = s.env.ledger().sequence() + 100;
+    s.client.approve_spending(
+        &fan,
+        &Some(creator.clone()),
+        &s.xlm,
+        &(3 * MIN_AMOUNT),
+        &expiration_ledger,
+    );
+    s.client.approve_spending(&fan, &None, &s.xlm, &(2 * MIN_AMOUNT), &expiration_ledger);
+    assert_eq!(s.client.get_allowances(&fan).len(), 2);
+
+    // The creator's own allowance is used first, the global one covers everyone else
+    assert!(spend(&creator, 2 * MIN_AMOUNT).is_ok());
+    assert_eq!(spend(&creator, 2 * MIN_AMOUNT), Err(Ok(Error::AllowanceExhausted)));
+    assert!(spend(&other, 2 * MIN_AMOUNT).is_ok());
+    assert_eq!(spend(&other, MIN_AMOUNT), Err(Ok(Error::AllowanceExhausted)));
+    assert_eq!(s.client.get_balance(&fan, &s.xlm), 16 * MIN_AMOUNT);
+
+    // Revoked or expired allowances no longer count
+    s.client.revoke_spending(&fan, &Some(creator.clone()), &s.xlm);
+    assert_eq!(spend(&creator, MIN_AMOUNT), Err(Ok(Error::AllowanceExhausted)));
+    s.client.approve_spending(&fan, &None, &s.xlm, &(5 * MIN_AMOUNT), &expiration_ledger);
+    s.env.ledger().with_mut(|li| li.sequence_number = expiration_ledger + 1);
+    assert_eq!(spend(&creator, MIN_AMOUNT), Err(Ok(Error::AllowanceMissing)));
+    assert_eq!(
+        s.client.try_revoke_spending(&fan, &Some(creator.clone()), &s.xlm),
+        Err(Ok(Error::AllowanceMissing))
+    );
+}
+
+#[test]
+fn test_admin_spend_is_idempotent_per_nonce() {
+    let s = setup();
+    let fan = Address::generate(&s.env);
+    let creator = Address::generate(&s.env);
+    s.token_admin.mint(&fan, &(10 * MIN_AMOUNT));
+    s.client.deposit(&fan, &s.xlm, &(10 * MIN_AMOUNT));
+    allow_spending(&s, &fan, &s.xlm, 10 * MIN_AMOUNT);
+    s.client.set_idempotency_window(&100);
+
+    let tx_type = String::from_str(&s.env, "tip");
+    let key = nonce(&s);
+    let amount = 4 * MIN_AMOUNT;
+    let spend = || s.client.admin_spend(&fan, &creator, &s.xlm, &amount, &tx_type, &None, &key);
+
+    let first = spend();
+    assert_eq!(
+        first,
+        SpendOutcome {
+            receipt_id: 1,
+            creator_amount: 36 * MIN_AMOUNT / 10,
+            platform_amount: 4 * MIN_AMOUNT / 10,
+        }
+    );
+    assert_eq!(spend(), first);
+    assert_eq!(s.client.get_balance(&fan, &s.xlm), 6 * MIN_AMOUNT);
+
+    // Once the window has passed the key is forgotten and charges again
+    s.env.ledger().with_mut(|li| li.sequence_number += 101);
+    assert_eq!(spend().receipt_id, first.receipt_id + 1);
+    assert_eq!(s.client.get_balance(&fan, &s.xlm), 2 * MIN_AMOUNT);
+    assert_eq!(s.client.try_set_idempotency_window(&0), Err(Ok(Error::InvalidWindow)));
+
+    // A window longer than any temporary entry can live would make every spend trap
+    let max_ttl = s.env.as_contract(&s.client.address, || s.env.storage().max_ttl());
+    assert_eq!(
+        s.client.try_set_idempotency_window(&(max_ttl + 1)),
+        Err(Ok(Error::InvalidWindow))
+    );
+    s.client.set_idempotency_window(&max_ttl);
+    let key = nonce(&s);
+    let long = s.client.admin_spend(&fan, &creator, &s.xlm, &MIN_AMOUNT, &tx_type, &None, &key);
+    assert_eq!(long.receipt_id, first.receipt_id + 2);
+}
+
+#[test]
+fn test_refunds_follow_the_receipt_split() {
+    let s = setup();
+    let fan = Address::generate(&s.env);
+    let creator = Address::generate(&s.env);
+    s.token_admin.mint(&fan, &(10 * MIN_AMOUNT));
+    s.client.deposit(&fan, &s.xlm, &(10 * MIN_AMOUNT));
+    allow_spending(&s, &fan, &s.xlm, 10 * MIN_AMOUNT);
+
+    let product_id = Some(String::from_str(&s.env, "video-42"));
+    let outcome = s.client.admin_spend(
+        &fan,
+        &creator,
+        &s.xlm,
+        &(10 * MIN_AMOUNT),
+        &String::from_str(&s.env, "nft"),
+        &product_id,
+        &nonce(&s),
+    );
+    let receipt = s.client.get_receipt(&outcome.receipt_id);
+    assert_eq!(receipt.from, fan);
+    assert_eq!(receipt.creator_amount, 9 * MIN_AMOUNT);
+    assert_eq!(receipt.platform_amount, MIN_AMOUNT);
+    assert_eq!(receipt.product_id, product_id);
+
+    // Partial refund comes back 90/10 from creator and platform
+    s.client.refund(&outcome.receipt_id, &(4 * MIN_AMOUNT));
+    assert_eq!(s.client.get_balance(&fan, &s.xlm), 4 * MIN_AMOUNT);
+    assert_eq!(s.client.get_balance(&creator, &s.xlm), 54 * MIN_AMOUNT / 10);
+    assert_eq!(s.client.get_treasury_balance(&s.xlm), 6 * MIN_AMOUNT / 10);
+
+    assert_eq!(
+        s.client.try_refund(&outcome.receipt_id, &(6 * MIN_AMOUNT + 1)),
+        Err(Ok(Error::RefundExceedsCharge))
+    );
+    let receipt = s.client.refund(&outcome.receipt_id, &(6 * MIN_AMOUNT));
+    assert_eq!(receipt.refunded, 10 * MIN_AMOUNT);
+    assert_eq!(s.client.get_balance(&fan, &s.xlm), 10 * MIN_AMOUNT);
+    assert_eq!(s.client.get_balance(&creator, &s.xlm), 0);
+    assert_eq!(s.client.get_treasury_balance(&s.xlm), 0);
+    assert_eq!(s.client.try_get_receipt(&99), Err(Ok(Error::ReceiptNotFound)));
+}
+
+#[test]
+fn test_history_is_paginated_per_address() {
+    let s = setup();
+    let fan = Address::generate(&s.env);
+    let creator = Address::generate(&s.env);
+    s.token_admin.mint(&fan, &(100 * MIN_AMOUNT));
+    allow_spending(&s, &fan, &s.xlm, 100 * MIN_AMOUNT);
+
+    for _ in 0..HISTORY_PAGE_SIZE {
+        s.client.deposit(&fan, &s.xlm, &MIN_AMOUNT);
+    }
+    let outcome = s.client.admin_spend(
+        &fan,
+        &creator,
+        &s.xlm,
+        &(10 * MIN_AMOUNT),
+        &String::from_str(&s.env, "tip"),
+        &None,
+        &nonce(&s),
+    );
+    s.client.withdraw(&fan, &s.xlm, &(5 * MIN_AMOUNT));
+
+    assert_eq!(s.client.get_history_len(&fan), HISTORY_PAGE_SIZE + 2);
+    assert_eq!(s.client.get_history(&fan, &0).len(), HISTORY_PAGE_SIZE);
+    let page = s.client.get_history(&fan, &1);
+    assert_eq!(page.len(), 2);
+    let spend = page.get(0).unwrap();
+    assert_eq!(spend.kind, HistoryKind::Spend);
+    assert_eq!(spend.counterparty, Some(creator.clone()));
+    assert_eq!(spend.amount, 10 * MIN_AMOUNT);
+    assert_eq!(spend.reference, Some(outcome.receipt_id));
+    assert_eq!(page.get(1).unwrap().kind, HistoryKind::Withdraw);
+    assert_eq!(s.client.get_history(&fan, &2).len(), 0);
+
+    let earnings = s.client.get_history(&creator, &0);
+    assert_eq!(earnings.len(), 1);
+    assert_eq!(earnings.get(0).unwrap().kind, HistoryKind::Earning);
+    assert_eq!(earnings.get(0).unwrap().amount, outcome.creator_amount);
+}
+
+#[test]
+fn test_split_tables_divide_creator_earnings() {
+    let s = setup();
+    let fan = Address::generate(&s.env);
+    let creator = Address::generate(&s.env);
+    let editor = Address::generate(&s.env);
+    s.token_admin.mint(&fan, &(10 * MIN_AMOUNT));
+    s.client.deposit(&fan, &s.xlm, &(10 * MIN_AMOUNT));
+    allow_spending(&s, &fan, &s.xlm, 10 * MIN_AMOUNT);
+
+    let share = |recipient: &Address, bps: u32| SplitShare { recipient: recipient.clone(), bps };
+    let short = Vec::from_array(&s.env, [share(&creator, 6000), share(&editor, 3000)]);
+    assert_eq!(s.client.try_set_splits(&creator, &short), Err(Ok(Error::InvalidSplits)));
+    let twice = Vec::from_array(&s.env, [share(&creator, 5000), share(&creator, 5000)]);
+    assert_eq!(s.client.try_set_splits(&creator, &twice), Err(Ok(Error::InvalidSplits)));
+
+    let shares = Vec::from_array(&s.env, [share(&creator, 6667), share(&editor, 3333)]);
+    s.client.set_splits(&creator, &shares);
+    assert_eq!(s.client.get_splits(&creator), shares);
+
+    // 9 XLM creator share: 6.0003 + dust to the creator, 2.9997 to the editor
+    let outcome = s.client.admin_spend(
+        &fan,
+        &creator,
+        &s.xlm,
+        &(10 * MIN_AMOUNT),
+        &String::from_str(&s.env, "video"),
+        &None,
+        &nonce(&s),
+    );
+    let receipt = s.client.get_receipt(&outcome.receipt_id);
+    assert_eq!(receipt.payouts.len(), 2);
+    assert_eq!(s.client.get_balance(&creator, &s.xlm), 60003000);
+    assert_eq!(s.client.get_balance(&editor, &s.xlm), 29997000);
+    assert_eq!(s.client.get_treasury_balance(&s.xlm), MIN_AMOUNT);
+
+    // Refunds claw back from each recipient in proportion
+    s.client.refund(&outcome.receipt_id, &(10 * MIN_AMOUNT));
+    assert_eq!(s.client.get_balance(&creator, &s.xlm), 0);
+    assert_eq!(s.client.get_balance(&editor, &s.xlm), 0);
+    assert_eq!(s.client.get_balance(&fan, &s.xlm), 10 * MIN_AMOUNT);
+
+    s.client.clear_splits(&creator);
+    assert_eq!(s.client.get_splits(&creator).len(), 0);
+}
+
+#[test]
+fn test_held_earnings_unlock_after_hold_period() {
+    let s = setup();
+    let fan = Address::generate(&s.env);
+    let creator = Address::generate(&s.env);
+    let victim = Address::generate(&s.env);
+    s.token_admin.mint(&fan, &(20 * MIN_AMOUNT));
+    s.client.deposit(&fan, &s.xlm, &(20 * MIN_AMOUNT));
+    allow_spending(&s, &fan, &s.xlm, 20 * MIN_AMOUNT);
+    assert_eq!(
+        s.client.try_set_hold_period(&(15 * 17280)),
+        Err(Ok(Error::InvalidHoldPeriod))
+    );
+    s.client.set_hold_period(&500);
+
+    let spend = || {
+        s.client.admin_spend(
+            &fan,
+            &creator,
+            &s.xlm,
+            &(10 * MIN_AMOUNT),
+            &String::from_str(&s.env, "tip"),
+            &None,
+            &nonce(&s),
+        )
+    };
+    spend();
+    let breakdown = s.client.get_balance_breakdown(&creator, &s.xlm);
+    assert_eq!(breakdown.available, 0);
+    assert_eq!(breakdown.pending, 9 * MIN_AMOUNT);
+    assert_eq!(breakdown.next_unlock_ledger, Some(720));
+    assert_eq!(
+        s.client.try_withdraw(&creator, &s.xlm, &MIN_AMOUNT),
+        Err(Ok(Error::InsufficientBalance))
+    );
+    // Platform fees are not held
+    assert_eq!(s.client.get_treasury_balance(&s.xlm), MIN_AMOUNT);
+
+    s.env.ledger().with_mut(|l| l.sequence_number = 720);
+    spend();
+    let breakdown = s.client.get_balance_breakdown(&creator, &s.xlm);
+    assert_eq!(breakdown.available, 9 * MIN_AMOUNT);
+    assert_eq!(breakdown.pending, 9 * MIN_AMOUNT);
+    s.client.withdraw(&creator, &s.xlm, &(9 * MIN_AMOUNT));
+    assert_eq!(s.token.balance(&creator), 9 * MIN_AMOUNT);
+
+    // Owner claws back the second, still held, payment
+    assert_eq!(
+        s.client.try_claw_back(&creator, &s.xlm, &(10 * MIN_AMOUNT), &victim),
+        Err(Ok(Error::InsufficientBalance))
+    );
+    s.client.claw_back(&creator, &s.xlm, &(9 * MIN_AMOUNT), &victim);
+    assert_eq!(s.client.get_balance(&victim, &s.xlm), 9 * MIN_AMOUNT);
+    let entry = s.client.get_history(&victim, &0).get_unchecked(0);
+    assert_eq!(entry.kind, HistoryKind::Refund);
+    assert_eq!(entry.counterparty, Some(creator.clone()));
+    assert_eq!(entry.amount, 9 * MIN_AMOUNT);
+    let breakdown = s.client.get_balance_breakdown(&creator, &s.xlm);
+    assert_eq!(breakdown.pending, 0);
+    assert_eq!(breakdown.next_unlock_ledger, None);
+}
+
+#[test]
+fn test_admin_spend_batch_atomic_and_best_effort() {
+    let s = setup();
+    let fan = Address::generate(&s.env);
+    let broke = Address::generate(&s.env);
+    let creator = Address::generate(&s.env);
+    s.token_admin.mint(&fan, &(10 * MIN_AMOUNT));
+    s.client.deposit(&fan, &s.xlm, &(10 * MIN_AMOUNT));
+    allow_spending(&s, &fan, &s.xlm, 10 * MIN_AMOUNT);
+    allow_spending(&s, &broke, &s.xlm, 10 * MIN_AMOUNT);
+
+    let item = |from: &Address| SpendItem {
+        from_user: from.clone(),
+        to_creator: creator.clone(),
+        asset: s.xlm.clone(),
+        amount: 2 * MIN_AMOUNT,
+        tx_type: String::from_str(&s.env, "tip"),
+        product_id: None,
+        nonce: nonce(&s),
+    };
+    let items = Vec::from_array(&s.env, [item(&broke), item(&fan)]);
+
+    // All-or-nothing: the unfunded item rolls back the whole batch
+    assert_eq!(
+        s.client.try_admin_spend_batch(&items, &true),
+        Err(Ok(Error::InsufficientBalance))
+    );
+    assert_eq!(s.client.get_balance(&fan, &s.xlm), 10 * MIN_AMOUNT);
+
+    let results = s.client.admin_spend_batch(&items, &false);
+    assert_eq!(results.len(), 2);
+    assert_eq!(results.get_unchecked(0), SpendResult::Failed(Error::InsufficientBalance as u32));
+    assert!(matches!(results.get_unchecked(1), SpendResult::Applied(_)));
+    assert_eq!(s.client.get_balance(&fan, &s.xlm), 8 * MIN_AMOUNT);
+    assert_eq!(s.client.get_balance(&creator, &s.xlm), 18 * MIN_AMOUNT / 10);
+
+    // Replaying the batch is a no-op thanks to the per-item nonces
+    assert_eq!(s.client.admin_spend_batch(&items, &false), results);
+    assert_eq!(s.client.get_balance(&fan, &s.xlm), 8 * MIN_AMOUNT);
+    assert_eq!(
+        s.client.try_admin_spend_batch(&Vec::new(&s.env), &false),
+        Err(Ok(Error::InvalidBatchSize))
+    );
+    let too_many = Vec::from_array(&s.env, [item(&fan), item(&fan), item(&fan)]);
+    assert_eq!(
+        s.client.try_admin_spend_batch(&too_many, &false),
+        Err(Ok(Error::InvalidBatchSize))
+    );
+}
+
+#[test]
+fn test_full_spend_batch_fits_transaction_limits() {
+    // Per-transaction network limits on ledger entries; the default test budget
+    // already enforces the instruction and memory limits
+    const TX_MAX_WRITE_ENTRIES: u32 = 25;
+    const TX_MAX_FOOTPRINT_ENTRIES: u32 = 40;
+
+    // Distinct fans and creators, so no two items share an entry
+    let s = setup();
+    let mut items = Vec::new(&s.env);
+    for _ in 0..MAX_BATCH_SPENDS {
+        let fan = Address::generate(&s.env);
+        s.token_admin.mint(&fan, &(2 * MIN_AMOUNT));
+        s.client.deposit(&fan, &s.xlm, &(2 * MIN_AMOUNT));
+        allow_spending(&s, &fan, &s.xlm, 2 * MIN_AMOUNT);
+        items.push_back(SpendItem {
+            from_user: fan,
+            to_creator: Address::generate(&s.env),
+            asset: s.xlm.clone(),
+            amount: 2 * MIN_AMOUNT,
+            tx_type: String::from_str(&s.env, "tip"),
+            product_id: None,
+            nonce: nonce(&s),
+        });
+    }
+    s.env.cost_estimate().budget().reset_default();
+    s.client.admin_spend_batch(&items, &true);
+
+    let resources = s.env.cost_estimate().resources();
+    assert!(resources.write_entries <= TX_MAX_WRITE_ENTRIES);
+    assert!(resources.read_entries + resources.write_entries <= TX_MAX_FOOTPRINT_ENTRIES);
+}
+
+#[test]
+fn test_platform_fees_accrue_to_weighted_treasury() {
+    let s = setup();
+    let fan = Address::generate(&s.env);
+    let creator = Address::generate(&s.env);
+    let ops = Address::generate(&s.env);
+    let reserve = Address::generate(&s.env);
+    s.token_admin.mint(&fan, &(30 * MIN_AMOUNT));
+    s.client.deposit(&fan, &s.xlm, &(30 * MIN_AMOUNT));
+    allow_spending(&s, &fan, &s.xlm, 30 * MIN_AMOUNT);
+
+    let share = |recipient: &Address, weight: u32| TreasuryShare {
+        recipient: recipient.clone(),
+        weight,
+    };
+    assert_eq!(s.client.get_treasury(), Vec::from_array(&s.env, [share(&s.owner, 1)]));
+    let zero = Vec::from_array(&s.env, [share(&ops, 0)]);
+    assert_eq!(s.client.try_set_treasury(&zero), Err(Ok(Error::InvalidTreasury)));
+    s.client.set_treasury(&Vec::from_array(&s.env, [share(&ops, 2), share(&reserve, 1)]));
+
+    s.client.admin_spend(
+        &fan,
+        &creator,
+        &s.xlm,
+        &(30 * MIN_AMOUNT),
+        &String::from_str(&s.env, "tip"),
+        &None,
+        &nonce(&s),
+    );
+    // Fees sit in the treasury pool, not in anyone's balance
+    assert_eq!(s.client.get_treasury_balance(&s.xlm), 3 * MIN_AMOUNT);
+    assert_eq!(s.client.get_balance(&s.owner, &s.xlm), 0);
+
+    assert_eq!(s.client.sweep_treasury(&s.xlm), 3 * MIN_AMOUNT);
+    assert_eq!(s.token.balance(&ops), 2 * MIN_AMOUNT);
+    assert_eq!(s.token.balance(&reserve), MIN_AMOUNT);
+    assert_eq!(s.client.get_treasury_balance(&s.xlm), 0);
+    assert_eq!(s.client.sweep_treasury(&s.xlm), 0);
+}
+
+#[test]
+fn test_refund_after_sweep_is_backed_by_the_owner() {
+    let s = setup();
+    let fan = Address::generate(&s.env);
+    let other = Address::generate(&s.env);
+    let creator = Address::generate(&s.env);
+    for (user, amount) in [(&fan, 10 * MIN_AMOUNT), (&other, 4 * MIN_AMOUNT)] {
+        s.token_admin.mint(user, &amount);
+        s.client.deposit(user, &s.xlm, &amount);
+        allow_spending(&s, user, &s.xlm, amount);
+    }
+
+    let spend = |from: &Address, amount: i128| {
+        s.client.admin_spend(
+            from,
+            &creator,
+            &s.xlm,
+            &amount,
+            &String::from_str(&s.env, "tip"),
+            &None,
+            &nonce(&s),
+        )
+    };
+    let outcome = spend(&fan, 10 * MIN_AMOUNT);
+    assert_eq!(s.client.sweep_treasury(&s.xlm), MIN_AMOUNT);
+    spend(&other, 4 * MIN_AMOUNT);
+
+    // The pool covers what it still holds; the owner pays in the already swept rest
+    s.client.refund(&outcome.receipt_id, &(10 * MIN_AMOUNT));
+    assert_eq!(s.client.get_treasury_balance(&s.xlm), 0);
+    assert_eq!(s.token.balance(&s.owner), 4 * MIN_AMOUNT / 10);
+    assert_eq!(s.client.get_balance(&fan, &s.xlm), 10 * MIN_AMOUNT);
+    assert_eq!(s.client.get_balance(&creator, &s.xlm), 36 * MIN_AMOUNT / 10);
+    assert_eq!(s.token.balance(&s.client.address), 136 * MIN_AMOUNT / 10);
+
+    s.client.withdraw(&fan, &s.xlm, &(10 * MIN_AMOUNT));
+    assert_eq!(s.token.balance(&fan), 10 * MIN_AMOUNT);
+}